@@ -0,0 +1,68 @@
+use crate::{parse::lines, Answer, Example, ParseError, Solution};
+
+const DAY: u8 = 1;
+
+pub fn part_1(depths: &[i64]) -> usize {
+    count_window_increases(depths, 1)
+}
+
+pub fn part_2(depths: &[i64]) -> usize {
+    count_window_increases(depths, 3)
+}
+
+fn count_window_increases(depths: &[i64], window_size: usize) -> usize {
+    depths
+        .windows(window_size + 1)
+        .filter(|w| w.last() > w.first())
+        .count()
+}
+
+pub fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+    lines(DAY, input)
+        .map(|line| line.parse(line.text))
+        .collect()
+}
+
+/// The examples from the puzzle description, with the answers it gives for them.
+pub fn examples() -> Vec<Example> {
+    vec![(
+        "199\n200\n208\n210\n200\n207\n240\n269\n260\n263",
+        Some(Answer::Int(7)),
+        Some(Answer::Int(5)),
+    )]
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = DAY;
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Vec<i64>) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Vec<i64>) -> Answer {
+        part_2(input).into()
+    }
+
+    fn examples() -> Vec<Example> {
+        examples()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = parse(examples()[0].0).unwrap();
+        assert_eq!(part_1(&input), 7);
+        assert_eq!(part_2(&input), 5);
+    }
+}
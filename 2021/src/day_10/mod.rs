@@ -0,0 +1,147 @@
+use itertools::Itertools;
+
+use crate::{parse::lines, Answer, Example, ParseError, Solution};
+
+const DAY: u8 = 10;
+/// Any more and the completion score could overflow 64 bits.
+const MAX_UNCLOSED: usize = 27;
+
+struct ParseErr {
+    c: char,
+}
+
+fn parse_line(line: &str) -> Result<Vec<char>, ParseErr> {
+    let mut stack = Vec::with_capacity(line.len());
+    for c in line.chars() {
+        if Some(&c) == stack.last() {
+            stack.pop();
+            continue;
+        }
+        stack.push(match c {
+            '(' => ')',
+            '[' => ']',
+            '{' => '}',
+            '<' => '>',
+            _ => return Err(ParseErr { c }),
+        });
+    }
+
+    Ok(stack)
+}
+
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    lines(DAY, input)
+        .map(|line| match line.text.find(|c| !"()[]{}<>".contains(c)) {
+            Some(bad) => Err(line.error(&line.text[bad..], "expected a bracket")),
+            None => match parse_line(line.text) {
+                // each bracket multiplies the completion score by 5
+                Ok(stack) if stack.len() > MAX_UNCLOSED => Err(line.error_at_end(format!(
+                    "expected at most {} unclosed brackets",
+                    MAX_UNCLOSED
+                ))),
+                _ => Ok(line.text.to_owned()),
+            },
+        })
+        .collect()
+}
+
+pub fn part_1(lines: &[String]) -> u64 {
+    lines
+        .iter()
+        .map(|line| parse_line(line))
+        .flat_map(Result::err)
+        .map(|ParseErr { c }| match c {
+            ')' => 3,
+            ']' => 57,
+            '}' => 1197,
+            '>' => 25137,
+            _ => panic!("Unexpected character {}", c),
+        })
+        .sum()
+}
+
+fn score_stack(stack: Vec<char>) -> u64 {
+    stack
+        .iter()
+        .rev()
+        .map(|&c| match c {
+            ')' => 1,
+            ']' => 2,
+            '}' => 3,
+            '>' => 4,
+            _ => panic!("Unexpected character {}", c),
+        })
+        .reduce(|score, c| score * 5 + c)
+        .unwrap_or(0)
+}
+
+pub fn part_2(lines: &[String]) -> u64 {
+    let scores: Vec<_> = lines
+        .iter()
+        .map(|line| parse_line(line))
+        .flat_map(Result::ok)
+        .map(score_stack)
+        .sorted_unstable()
+        .collect();
+
+    scores.get(scores.len() / 2).copied().unwrap_or(0)
+}
+
+/// The examples from the puzzle description, with the answers it gives for them.
+pub fn examples() -> Vec<Example> {
+    vec![(
+        "\
+[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+(((({<>}<{<{<>}{[]{[]{}
+[[<[([]))<([[{}[[()]]]
+[{[{({}]{}}([{[{{{}}([]
+{<[[]]>}<{[{[{[]{()[[[]
+[<(<(<(<{}))><([]([]()
+<{([([[(<>()){}]>(<<{{
+<{([{{}}[<[[[<>{}]]]>[]]",
+        Some(Answer::Int(26397)),
+        Some(Answer::Int(288957)),
+    )]
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = DAY;
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Vec<String>) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Vec<String>) -> Answer {
+        part_2(input).into()
+    }
+
+    fn examples() -> Vec<Example> {
+        examples()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = parse(examples()[0].0).unwrap();
+
+        assert_eq!(26397, part_1(&input));
+        assert_eq!(288957, part_2(&input));
+
+        // completing this would score more than fits in 64 bits
+        let err = parse("[({(<([({(<[({((()<([({(<([({(()<(").unwrap_err();
+        assert_eq!((1, 35), (err.line, err.column));
+    }
+}
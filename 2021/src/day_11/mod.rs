@@ -0,0 +1,170 @@
+use crate::{
+    cancel,
+    grid::{Grid, Point},
+    trace::{self, Observer, Snapshot},
+    Answer, Example, Param, Params, ParseError, Part, Solution,
+};
+
+const DAY: u8 = 11;
+
+pub fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse_digits(DAY, input)
+}
+
+/// Advances one step, returning how many octopuses flashed.
+pub(crate) fn generation(grid: &mut Grid<u8>) -> u64 {
+    grid.iter_mut().for_each(|c| *c += 1);
+    // an explicit stack rather than recursion, since a flash can spread right across a big grid
+    let mut full: Vec<Point> = grid.points().filter(|&p| grid[p] > 9).collect();
+    let mut flashes = 0;
+    while let Some(p) = full.pop() {
+        // octopuses that already flashed this step are back at 0
+        if grid[p] <= 9 {
+            continue;
+        }
+        grid[p] = 0;
+        flashes += 1;
+        for n in grid.neighbours_8(p).collect::<Vec<_>>() {
+            if grid[n] != 0 {
+                grid[n] += 1;
+                if grid[n] > 9 {
+                    full.push(n);
+                }
+            }
+        }
+    }
+    flashes
+}
+
+/// Shows the energy levels, which are shaded by level so flashing octopuses are white.
+fn snapshot(step: usize, grid: &Grid<u8>) -> Snapshot {
+    Snapshot::new(step, grid.to_string(), 10, |c| {
+        c.to_digit(10).unwrap() as u8
+    })
+}
+
+const STEPS: Param = Param {
+    name: "steps",
+    help: "how many steps part 1 counts the flashes over",
+    default: 100,
+    range: 0..=1_000_000,
+};
+
+fn count_flashes(grid: &Grid<u8>, steps: usize, observer: &mut dyn Observer) -> u64 {
+    let mut grid = grid.clone();
+    trace::show(observer, || snapshot(0, &grid));
+    let mut flashes = 0;
+    for step in 1..=steps {
+        flashes += generation(&mut grid);
+        trace::show(observer, || snapshot(step, &grid));
+    }
+
+    flashes
+}
+
+fn first_sync(grid: &Grid<u8>, observer: &mut dyn Observer) -> usize {
+    let mut grid = grid.clone();
+    trace::show(observer, || snapshot(0, &grid));
+    (1..)
+        .find(|&step| {
+            // a grid that never synchronizes would keep this going forever
+            cancel::check();
+            let flashes = generation(&mut grid);
+            trace::show(observer, || snapshot(step, &grid));
+            flashes == grid.len() as u64
+        })
+        .unwrap()
+}
+
+pub fn part_1(grid: &Grid<u8>) -> u64 {
+    count_flashes(grid, STEPS.default as usize, &mut ())
+}
+
+pub fn part_2(grid: &Grid<u8>) -> usize {
+    first_sync(grid, &mut ())
+}
+
+/// The examples from the puzzle description, with the answers it gives for them.
+pub fn examples() -> Vec<Example> {
+    vec![(
+        "\
+5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526",
+        Some(Answer::Int(1656)),
+        Some(Answer::Int(195)),
+    )]
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = DAY;
+    type Input = Grid<u8>;
+    const PARAMS: &'static [Param] = &[STEPS];
+
+    fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Grid<u8>) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Grid<u8>) -> Answer {
+        part_2(input).into()
+    }
+
+    fn solve_with(input: &Grid<u8>, part: Part, params: &Params) -> Answer {
+        Self::trace(input, part, params, &mut ()).unwrap()
+    }
+
+    fn trace(
+        input: &Grid<u8>,
+        part: Part,
+        params: &Params,
+        observer: &mut dyn Observer,
+    ) -> Option<Answer> {
+        Some(match part {
+            Part::One => count_flashes(input, params.get_usize(&STEPS), observer).into(),
+            Part::Two => first_sync(input, observer).into(),
+        })
+    }
+
+    fn examples() -> Vec<Example> {
+        examples()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = parse(examples()[0].0).unwrap();
+
+        assert_eq!(1656, part_1(&input));
+        assert_eq!(195, part_2(&input));
+
+        let mut snapshots = vec![];
+        assert_eq!(195, first_sync(&input, &mut snapshots));
+        assert_eq!(196, snapshots.len());
+        assert!(snapshots[195].image.iter().all(|&shade| shade == 0));
+        assert!(snapshots[0].text.starts_with("5483143223\n2745854711\n"));
+
+        let mut params = Params::default();
+        params.set(Day11::PARAMS, "steps=10").unwrap();
+        assert_eq!(
+            Answer::Int(204),
+            Day11::solve_with(&input, Part::One, &params)
+        );
+    }
+}
@@ -0,0 +1,228 @@
+use rustc_hash::FxHashMap;
+
+use crate::{
+    parse::{lines, Line},
+    Answer, Example, ParseError, Solution,
+};
+
+const DAY: u8 = 12;
+
+type Graph = Vec<Vec<Cave>>;
+
+const START_NAME: &str = "start";
+const END_NAME: &str = "end";
+const END_ID: u8 = 0;
+const START_ID: u8 = 1;
+const START: Cave = Cave::Small(START_ID);
+const END: Cave = Cave::Small(END_ID);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Cave {
+    Big(u8),
+    Small(u8),
+}
+use Cave::*;
+
+impl Cave {
+    fn idx(self) -> usize {
+        match self {
+            Small(v) | Big(v) => v as usize,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct VisitTracker {
+    counts: Vec<u8>,
+    can_visit_twice: bool,
+}
+
+impl VisitTracker {
+    fn new(can_visit_twice: bool, size: usize) -> Self {
+        let mut counts = vec![0; size];
+        counts[END.idx()] = 2;
+        counts[START.idx()] = 2;
+        Self {
+            counts,
+            can_visit_twice,
+        }
+    }
+
+    fn can_visit(&self, s: Cave) -> bool {
+        let c = self.counts[s.idx()];
+        c == 0 || (c == 1 && self.can_visit_twice)
+    }
+
+    fn add(&mut self, s: Cave) {
+        self.counts[s.idx()] += 1;
+        self.can_visit_twice = self.can_visit_twice && self.counts[s.idx()] < 2;
+    }
+}
+
+fn num_paths(graph: &[Vec<Cave>], start: Cave, tracker: &VisitTracker) -> usize {
+    graph[start.idx()]
+        .iter()
+        .map(|&n| {
+            if n == END {
+                1
+            } else if tracker.can_visit(n) {
+                if matches!(n, Small(_)) {
+                    let mut new_tracker = tracker.clone();
+                    new_tracker.add(n);
+                    num_paths(graph, n, &new_tracker)
+                } else {
+                    num_paths(graph, n, tracker)
+                }
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+#[inline]
+fn add_cave<'a>(
+    cave_by_name: &mut FxHashMap<&'a str, Cave>,
+    line: &Line,
+    name: &'a str,
+    last_id: &mut u8,
+) -> Result<Cave, ParseError> {
+    if let Some(&cave) = cave_by_name.get(name) {
+        return Ok(cave);
+    }
+    *last_id = last_id
+        .checked_add(1)
+        .ok_or_else(|| line.error(name, "expected at most 256 caves"))?;
+    let cave = if !name.is_empty() && name.chars().all(|c| c.is_ascii_lowercase()) {
+        Small(*last_id)
+    } else if !name.is_empty() && name.chars().all(|c| c.is_ascii_uppercase()) {
+        Big(*last_id)
+    } else {
+        return Err(line.error(name, "expected a lowercase or uppercase cave name"));
+    };
+    cave_by_name.insert(name, cave);
+    Ok(cave)
+}
+
+pub fn parse(input: &str) -> Result<Graph, ParseError> {
+    let mut cave_by_name = FxHashMap::default();
+    cave_by_name.insert(START_NAME, START);
+    cave_by_name.insert(END_NAME, END);
+    let mut last_id = std::cmp::max(START_ID, END_ID);
+    let mut graph = vec![Vec::new(); last_id as usize + 1];
+    for line in lines(DAY, input) {
+        let (src, dest) = line.split_once(line.text, "-")?;
+        let src = add_cave(&mut cave_by_name, &line, src, &mut last_id)?;
+        let dest = add_cave(&mut cave_by_name, &line, dest, &mut last_id)?;
+        if let (Big(_), Big(_)) = (src, dest) {
+            // there would be infinitely many paths bouncing between the two
+            return Err(line.error(line.text, "expected at most one big cave"));
+        }
+
+        graph.resize(graph.len().max(last_id as usize + 1), vec![]);
+        graph[src.idx()].push(dest);
+        graph[dest.idx()].push(src);
+    }
+
+    Ok(graph)
+}
+
+pub fn part_1(graph: &Graph) -> usize {
+    num_paths(graph, START, &VisitTracker::new(false, graph.len()))
+}
+
+pub fn part_2(graph: &Graph) -> usize {
+    num_paths(graph, START, &VisitTracker::new(true, graph.len()))
+}
+
+/// The examples from the puzzle description, with the answers it gives for them.
+pub fn examples() -> Vec<Example> {
+    vec![
+        (
+            "\
+start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end",
+            Some(Answer::Int(10)),
+            Some(Answer::Int(36)),
+        ),
+        (
+            "\
+dc-end
+HN-start
+start-kj
+dc-start
+dc-HN
+LN-dc
+HN-end
+kj-sa
+kj-HN
+kj-dc",
+            Some(Answer::Int(19)),
+            Some(Answer::Int(103)),
+        ),
+        (
+            "\
+fs-end
+he-DX
+fs-he
+start-DX
+pj-DX
+end-zg
+zg-sl
+zg-pj
+pj-he
+RW-he
+fs-DX
+pj-RW
+zg-RW
+start-pj
+he-WI
+zg-he
+pj-fs
+start-RW",
+            Some(Answer::Int(226)),
+            Some(Answer::Int(3509)),
+        ),
+    ]
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = DAY;
+    type Input = Graph;
+
+    fn parse(input: &str) -> Result<Graph, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Graph) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Graph) -> Answer {
+        part_2(input).into()
+    }
+
+    fn examples() -> Vec<Example> {
+        examples()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = parse(examples()[0].0).unwrap();
+
+        assert_eq!(10, part_1(&input));
+        assert_eq!(36, part_2(&input));
+    }
+}
@@ -0,0 +1,251 @@
+use rustc_hash::FxHashSet;
+use std::cmp::max;
+
+use itertools::Itertools;
+
+use crate::{
+    parse::lines,
+    trace::{self, Observer, Snapshot},
+    Answer, Example, Params, ParseError, Part, Solution,
+};
+
+const DAY: u8 = 13;
+
+type Coords = (usize, usize);
+
+#[derive(Clone, Copy)]
+struct Fold {
+    is_1st_dim: bool,
+    val: usize,
+}
+
+#[derive(Clone)]
+pub struct Grid {
+    values: FxHashSet<Coords>,
+    folds: Vec<Fold>,
+}
+
+pub fn parse(input: &str) -> Result<Grid, ParseError> {
+    let mut folds = vec![];
+    let mut values = FxHashSet::default();
+    for line in lines(DAY, input).filter(|line| !line.text.is_empty()) {
+        match line.text.strip_prefix("fold along ") {
+            Some(fold) => {
+                let (axis, val) = line.split_once(fold, "=")?;
+                let is_1st_dim = match axis {
+                    "x" => false,
+                    "y" => true,
+                    _ => return Err(line.error(axis, "expected 'x' or 'y'")),
+                };
+                folds.push(Fold {
+                    is_1st_dim,
+                    val: line.parse(val)?,
+                })
+            }
+            None if folds.is_empty() => {
+                let (x, y) = line.pair(line.text, ",")?;
+                values.insert((y, x));
+            }
+            None => return Err(line.error(line.text, "expected \"fold along \"")),
+        }
+    }
+
+    folds.reverse();
+    Ok(Grid { values, folds })
+}
+
+fn folded_dest(fold: Fold, p: Coords) -> Option<Coords> {
+    let mut coord = if fold.is_1st_dim { p.0 } else { p.1 };
+    if coord <= fold.val {
+        Some(p)
+    } else if 2 * fold.val >= coord {
+        coord = 2 * fold.val - coord;
+        if fold.is_1st_dim {
+            Some((coord, p.1))
+        } else {
+            Some((p.0, coord))
+        }
+    } else {
+        None
+    }
+}
+
+fn fold_grid(grid: &mut Grid) {
+    let fold = match grid.folds.pop() {
+        None => return,
+        Some(v) => v,
+    };
+
+    grid.values = grid
+        .values
+        .iter()
+        .flat_map(|&p| folded_dest(fold, p))
+        .collect();
+}
+
+fn render(grid: &Grid) -> String {
+    let max_1 = grid.values.iter().fold(0, |a, p| max(a, p.1));
+
+    let mut out = "\n".to_string();
+    let mut cur_row = 0;
+    for (row, g) in &grid.values.iter().sorted().group_by(|p| p.0) {
+        while row > cur_row {
+            out += "\n";
+            cur_row += 1;
+        }
+
+        let mut s = ".".repeat(max_1 + 1);
+        g.for_each(|p| s.replace_range(p.1..p.1 + 1, "#"));
+        out += &s;
+    }
+
+    out
+}
+
+/// Draws the whole sheet of paper, which is `size` in (rows, columns).
+fn snapshot(step: usize, grid: &Grid, (rows, cols): Coords) -> Snapshot {
+    let text = (0..rows)
+        .map(|y| {
+            (0..cols)
+                .map(|x| {
+                    if grid.values.contains(&(y, x)) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>()
+        })
+        .join("\n");
+    Snapshot::new(step, text, 2, |c| (c == '#') as u8)
+}
+
+/// Makes up to `num_folds` of the folds, showing `observer` the paper after each one.
+fn fold_paper(grid: &Grid, num_folds: usize, observer: &mut dyn Observer) -> Grid {
+    let mut grid = grid.clone();
+    // the paper is as big as it needs to be to hold the dots
+    let mut size = grid.values.iter().fold((0, 0), |(rows, cols), p| {
+        (max(rows, p.0 + 1), max(cols, p.1 + 1))
+    });
+    trace::show(observer, || snapshot(0, &grid, size));
+    for step in 1..=num_folds {
+        let Some(&fold) = grid.folds.last() else {
+            break;
+        };
+        fold_grid(&mut grid);
+        if fold.is_1st_dim {
+            size.0 = size.0.min(fold.val);
+        } else {
+            size.1 = size.1.min(fold.val);
+        }
+        trace::show(observer, || snapshot(step, &grid, size));
+    }
+    grid
+}
+
+pub fn part_1(grid: &Grid) -> usize {
+    fold_paper(grid, 1, &mut ()).values.len()
+}
+
+pub fn part_2(grid: &Grid) -> String {
+    render(&fold_paper(grid, usize::MAX, &mut ()))
+}
+
+/// The examples from the puzzle description, with the answers it gives for them.
+pub fn examples() -> Vec<Example> {
+    vec![(
+        "\
+6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5",
+        Some(Answer::Int(17)),
+        Some(Answer::Text(
+            "\n#####\n#...#\n#...#\n#...#\n#####".to_string(),
+        )),
+    )]
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = DAY;
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Grid, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Grid) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Grid) -> Answer {
+        part_2(input).into()
+    }
+
+    fn trace(
+        input: &Grid,
+        part: Part,
+        _params: &Params,
+        observer: &mut dyn Observer,
+    ) -> Option<Answer> {
+        Some(match part {
+            Part::One => fold_paper(input, 1, observer).values.len().into(),
+            Part::Two => render(&fold_paper(input, usize::MAX, observer)).into(),
+        })
+    }
+
+    fn examples() -> Vec<Example> {
+        examples()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = parse(examples()[0].0).unwrap();
+
+        assert_eq!(17, part_1(&input));
+        assert_eq!(
+            "
+#####
+#...#
+#...#
+#...#
+#####",
+            part_2(&input)
+        );
+
+        let mut snapshots = vec![];
+        fold_paper(&input, usize::MAX, &mut snapshots);
+        let sizes = snapshots
+            .iter()
+            .map(|s| (s.image.width(), s.image.height()));
+        assert_eq!(vec![(11, 15), (11, 7), (5, 7)], sizes.collect::<Vec<_>>());
+        assert_eq!(
+            "#####\n#...#\n#...#\n#...#\n#####\n.....\n.....",
+            snapshots[2].text
+        );
+    }
+}
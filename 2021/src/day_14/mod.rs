@@ -0,0 +1,298 @@
+use itertools::Itertools;
+use rustc_hash::FxHashMap;
+
+use crate::{
+    num::{BigUint, Unsigned},
+    parse::{end_of_input, lines},
+    Answer, Example, Param, Params, ParseError, Part, Solution,
+};
+
+const DAY: u8 = 14;
+
+type Id = usize;
+type Pair = (Id, Id);
+struct Rule {
+    from: Pair,
+    to: Id,
+}
+
+#[derive(Clone)]
+pub struct Poly {
+    template: Vec<Id>,
+    mapping: Vec<Option<Id>>,
+    max_id: Id,
+    /// How many times each pair appears in the template.
+    pair_counts: Vec<u64>,
+}
+
+impl Poly {
+    fn new(template: Vec<Id>, rules: Vec<Rule>) -> Poly {
+        let max_id = rules.iter().fold(0, |max, r| {
+            *[r.from.0, r.from.1, r.to, max].iter().max().unwrap_or(&max)
+        });
+        let max_id = template.iter().copied().fold(max_id, Id::max) + 1;
+
+        let mut poly = Poly {
+            template: template.clone(),
+            max_id,
+            mapping: vec![None; max_id * max_id],
+            pair_counts: vec![0; max_id * max_id],
+        };
+
+        for r in rules {
+            let idx = poly.pair_idx(r.from);
+            poly.mapping[idx] = Some(r.to);
+        }
+
+        for p in template.windows(2) {
+            poly.inc_pair((p[0], p[1]), 1)
+        }
+
+        poly
+    }
+
+    fn pair_idx(&self, pair: Pair) -> Id {
+        let pair = (pair.0, pair.1);
+        pair.0 * self.max_id + pair.1
+    }
+
+    fn inc_pair(&mut self, pair: Pair, inc: u64) {
+        let idx = self.pair_idx(pair);
+        self.pair_counts[idx] += inc;
+    }
+
+    fn expand(&self, pair: Pair) -> Option<Id> {
+        self.mapping[self.pair_idx(pair)]
+    }
+
+    fn generation<N: Unsigned>(&self, pair_counts: &[N]) -> Option<Vec<N>> {
+        let mut new_counts = vec![N::from_u64(0); self.max_id * self.max_id];
+        for i in 0..self.max_id {
+            for j in 0..self.max_id {
+                let pair = (i, j);
+                if let Some(to) = self.expand(pair) {
+                    let count = &pair_counts[self.pair_idx(pair)];
+                    for idx in [self.pair_idx((i, to)), self.pair_idx((to, j))] {
+                        new_counts[idx] = new_counts[idx].try_add(count)?;
+                    }
+                }
+            }
+        }
+        Some(new_counts)
+    }
+
+    /// Applies up to `steps` steps to `pair_counts`, stopping before a step whose counts would
+    /// overflow `N`, and returns how many steps were applied.
+    fn advance<N: Unsigned>(&self, pair_counts: &mut Vec<N>, steps: i64) -> i64 {
+        for done in 0..steps {
+            match self.generation(pair_counts) {
+                Some(next) => *pair_counts = next,
+                None => return done,
+            }
+        }
+        steps
+    }
+
+    /// The difference between the most and least common elements after `n_iters` steps, counted
+    /// in `N`, or `None` if they're too common for it.
+    pub fn solve<N: Unsigned>(&self, n_iters: i64) -> Option<N> {
+        let mut pair_counts: Vec<N> = self.pair_counts.iter().map(|&n| N::from_u64(n)).collect();
+        if self.advance(&mut pair_counts, n_iters) < n_iters {
+            return None;
+        }
+        self.difference(&pair_counts)
+    }
+
+    /// The difference between the most and least common elements in a polymer with these pair
+    /// counts, or `None` if they're too common for `N`.
+    fn difference<N: Unsigned>(&self, pair_counts: &[N]) -> Option<N> {
+        // every element is counted twice, as the first and second of a pair, apart from the ends
+        let mut counts = vec![N::from_u64(0); self.max_id];
+        for i in 0..self.max_id {
+            for j in 0..self.max_id {
+                let pair_count = &pair_counts[self.pair_idx((i, j))];
+                counts[i] = counts[i].try_add(pair_count)?;
+                counts[j] = counts[j].try_add(pair_count)?;
+            }
+        }
+        for end in [
+            self.template.first().unwrap(),
+            self.template.last().unwrap(),
+        ] {
+            counts[*end] = counts[*end].try_add(&N::from_u64(1))?;
+        }
+
+        let max = counts.iter().max().unwrap().div_small(2);
+        let min = counts.iter().min().unwrap().div_small(2);
+        Some(max.minus(&min))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Poly, ParseError> {
+    let mut idx_map = FxHashMap::default();
+    let mut next_id = 0;
+    let mut get_id = |b: u8, idx_map: &mut FxHashMap<u8, Id>| match idx_map.get(&b) {
+        Some(id) => *id,
+        None => {
+            idx_map.insert(b, next_id);
+            next_id += 1;
+            next_id - 1
+        }
+    };
+
+    let mut lines = lines(DAY, input);
+    let template = match lines.next() {
+        Some(line) if !line.text.is_empty() => line.text,
+        _ => return Err(end_of_input(DAY, input, "expected the polymer template")),
+    };
+    let template = template.bytes().map(|b| get_id(b, &mut idx_map)).collect();
+
+    let rules = lines
+        .filter(|line| !line.text.is_empty())
+        .map(|line| {
+            let (from, to) = line.split_once(line.text, " -> ")?;
+            if from.len() != 2 || to.len() != 1 {
+                return Err(line.error(line.text, "expected a rule like \"AB -> C\""));
+            }
+            let (a, b, to) = from
+                .bytes()
+                .chain(to.bytes())
+                .map(|b| get_id(b, &mut idx_map))
+                .collect_tuple()
+                .unwrap();
+            Ok(Rule { from: (a, b), to })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Poly::new(template, rules))
+}
+
+// the polymer doubles in length every step, so each of its hundred or so pair counts gains a
+// digit every few steps, and the last step allowed takes about a second
+const PART_1_STEPS: Param = Param {
+    name: "part1_steps",
+    help: "how many steps of insertion part 1 applies",
+    default: 10,
+    range: 0..=10_000,
+};
+const PART_2_STEPS: Param = Param {
+    name: "part2_steps",
+    help: "how many steps of insertion part 2 applies",
+    default: 40,
+    range: 0..=10_000,
+};
+
+pub fn part_1(poly: &Poly) -> u64 {
+    poly.solve(PART_1_STEPS.default).unwrap()
+}
+
+pub fn part_2(poly: &Poly) -> u64 {
+    poly.solve(PART_2_STEPS.default).unwrap()
+}
+
+/// The examples from the puzzle description, with the answers it gives for them.
+pub fn examples() -> Vec<Example> {
+    vec![(
+        "\
+NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C",
+        Some(Answer::Int(1588)),
+        Some(Answer::Int(2188189693529)),
+    )]
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = DAY;
+    type Input = Poly;
+    const PARAMS: &'static [Param] = &[PART_1_STEPS, PART_2_STEPS];
+
+    fn parse(input: &str) -> Result<Poly, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Poly) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Poly) -> Answer {
+        part_2(input).into()
+    }
+
+    fn solve_with(input: &Poly, part: Part, params: &Params) -> Answer {
+        let steps = match part {
+            Part::One => params.get(&PART_1_STEPS),
+            Part::Two => params.get(&PART_2_STEPS),
+        };
+        // a u64 is enough for the puzzle itself, and the slower types only take over for longer
+        // runs, from the step where the narrower ones overflowed
+        let mut narrow = input.pair_counts.clone();
+        let mut done = input.advance(&mut narrow, steps);
+        if let (true, Some(difference)) = (done == steps, input.difference(&narrow)) {
+            return difference.into();
+        }
+        let mut wide = narrow.into_iter().map(u128::from).collect();
+        done += input.advance(&mut wide, steps - done);
+        if let (true, Some(difference)) = (done == steps, input.difference(&wide)) {
+            return difference.into();
+        }
+        let mut big = wide.into_iter().map(BigUint::from).collect();
+        input.advance(&mut big, steps - done);
+        input.difference(&big).unwrap().into()
+    }
+
+    fn examples() -> Vec<Example> {
+        examples()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = parse(examples()[0].0).unwrap();
+
+        assert_eq!(1588, part_1(&input));
+        assert_eq!(2188189693529, part_2(&input));
+
+        assert_eq!(None, input.solve::<u64>(70));
+        let wide = input.solve::<u128>(70).unwrap();
+        assert_eq!(
+            wide.to_string(),
+            input.solve::<BigUint>(70).unwrap().to_string()
+        );
+        let mut params = Params::default();
+        params.set(Day14::PARAMS, "part2_steps=70").unwrap();
+        assert_eq!(
+            Answer::from(wide),
+            Day14::solve_with(&input, Part::Two, &params)
+        );
+        params.set(Day14::PARAMS, "part2_steps=200").unwrap();
+        let Answer::Big(n) = Day14::solve_with(&input, Part::Two, &params) else {
+            panic!("expected an answer too big for an i128")
+        };
+        assert_eq!(
+            "3213876088504849459628933947091293102955756486973947218116623",
+            n.to_string()
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use crate::{
+    cancel,
+    grid::{Grid, Point},
+    Answer, Example, ParseError, Solution,
+};
+
+const DAY: u8 = 15;
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct Node {
+    potential_cost: usize,
+    current_cost: usize,
+    point: Point,
+}
+
+fn solve(grid: &Grid<u8>) -> Option<usize> {
+    let target = (grid.width() - 1, grid.height() - 1);
+    let mut best_current_costs = vec![usize::MAX; grid.len()];
+    let mut heap = BinaryHeap::new();
+    heap.push(Reverse(Node {
+        potential_cost: target.0 + target.1,
+        current_cost: 0,
+        point: (0, 0),
+    }));
+
+    loop {
+        cancel::check();
+        let next = match heap.pop() {
+            None => return None,
+            Some(next) => next.0,
+        };
+        if next.point == target {
+            return Some(next.current_cost);
+        }
+        for point in grid.neighbours_4(next.point) {
+            let current_cost = next.current_cost + grid[point] as usize;
+            let idx = grid.index_of(point);
+            if current_cost < best_current_costs[idx] {
+                best_current_costs[idx] = current_cost;
+                let node = Node {
+                    potential_cost: current_cost + (target.0 - point.0) + (target.1 - point.1),
+                    current_cost,
+                    point,
+                };
+                heap.push(Reverse(node));
+            }
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse_chars(
+        DAY,
+        input,
+        |c| c.to_digit(10).filter(|&d| d > 0).map(|d| d as u8),
+        "a risk from 1 to 9",
+    )
+}
+
+fn expand(grid: &Grid<u8>, factor: usize) -> Grid<u8> {
+    let (width, height) = (grid.width(), grid.height());
+    Grid::from_fn(factor * width, factor * height, |(x, y)| {
+        let base_risk = grid[(x % width, y % height)];
+        1 + (base_risk - 1 + (x / width) as u8 + (y / height) as u8) % 9
+    })
+}
+
+pub fn part_1(grid: &Grid<u8>) -> usize {
+    solve(grid).expect("A path to the end should exist")
+}
+
+pub fn part_2(grid: &Grid<u8>) -> usize {
+    let grid = expand(grid, 5);
+    solve(&grid).expect("A path to the end should exist")
+}
+
+/// The examples from the puzzle description, with the answers it gives for them.
+pub fn examples() -> Vec<Example> {
+    vec![(
+        "\
+1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581",
+        Some(Answer::Int(40)),
+        Some(Answer::Int(315)),
+    )]
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = DAY;
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Grid<u8>) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Grid<u8>) -> Answer {
+        part_2(input).into()
+    }
+
+    fn examples() -> Vec<Example> {
+        examples()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = parse(examples()[0].0).unwrap();
+
+        assert_eq!(40, part_1(&input));
+        assert_eq!(315, part_2(&input));
+
+        // a grid wider than it is tall
+        let input = parse("19111\n11191").unwrap();
+        assert_eq!(7, part_1(&input));
+    }
+}
@@ -0,0 +1,400 @@
+use std::{fmt::Display, mem};
+
+use crate::{
+    parse::{first_line, Line},
+    Answer, Example, ParseError, Solution,
+};
+
+const DAY: u8 = 16;
+
+const LITERAL_VALUE_ID: u64 = 4;
+/// Deeper packets could overflow the stack, and real transmissions don't come close.
+const MAX_DEPTH: usize = 256;
+
+/// What an operator packet does with the values of its subpackets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Sum,
+    Product,
+    Minimum,
+    Maximum,
+    GreaterThan,
+    LessThan,
+    EqualTo,
+}
+use Operation::*;
+
+impl Operation {
+    /// The operation with a packet type ID, or `None` for 4, which is a literal value.
+    pub fn from_id(id: u8) -> Option<Operation> {
+        Some(match id {
+            0 => Sum,
+            1 => Product,
+            2 => Minimum,
+            3 => Maximum,
+            5 => GreaterThan,
+            6 => LessThan,
+            7 => EqualTo,
+            _ => return None,
+        })
+    }
+
+    pub fn id(self) -> u8 {
+        match self {
+            Sum => 0,
+            Product => 1,
+            Minimum => 2,
+            Maximum => 3,
+            GreaterThan => 5,
+            LessThan => 6,
+            EqualTo => 7,
+        }
+    }
+
+    /// Whether the operation compares exactly two subpackets, rather than taking one or more.
+    pub fn is_comparison(self) -> bool {
+        matches!(self, GreaterThan | LessThan | EqualTo)
+    }
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Sum => "sum",
+            Product => "product",
+            Minimum => "min",
+            Maximum => "max",
+            GreaterThan => "gt",
+            LessThan => "lt",
+            EqualTo => "eq",
+        };
+        f.write_str(name)
+    }
+}
+
+/// What a packet holds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PacketContent {
+    Literal {
+        value: u64,
+    },
+    Operator {
+        operator: Operation,
+        subpackets: Vec<Packet>,
+    },
+}
+
+/// A packet of the BITS transmission, holding either a literal value or an operator applied to
+/// its subpackets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Packet {
+    content: PacketContent,
+    version: u8,
+}
+
+impl Packet {
+    /// Creates a literal value packet. Versions have 3 bits, so only the lowest 3 are kept.
+    pub fn literal(version: u8, value: u64) -> Packet {
+        Packet {
+            content: PacketContent::Literal { value },
+            version: version & 0b111,
+        }
+    }
+
+    /// Creates an operator packet. Versions have 3 bits, so only the lowest 3 are kept.
+    ///
+    /// Panics if comparisons don't get exactly 2 subpackets or other operators get none.
+    pub fn operator(version: u8, operator: Operation, subpackets: Vec<Packet>) -> Packet {
+        match (operator.is_comparison(), subpackets.len()) {
+            (true, 2) | (false, 1..) => {}
+            (true, n) => panic!("{} takes exactly 2 subpackets, not {}", operator, n),
+            (false, _) => panic!("{} takes at least 1 subpacket", operator),
+        }
+        Packet {
+            content: PacketContent::Operator {
+                operator,
+                subpackets,
+            },
+            version: version & 0b111,
+        }
+    }
+
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    pub fn content(&self) -> &PacketContent {
+        &self.content
+    }
+
+    /// The sum of the versions of this packet and all the packets inside it.
+    pub fn version_sum(&self) -> u32 {
+        self.version as u32
+            + match &self.content {
+                PacketContent::Literal { value: _ } => 0,
+                PacketContent::Operator {
+                    operator: _,
+                    subpackets,
+                } => subpackets.iter().map(Packet::version_sum).sum::<u32>(),
+            }
+    }
+
+    /// The packet's value, or `None` if working it out overflows.
+    pub fn value(&self) -> Option<u64> {
+        match &self.content {
+            PacketContent::Literal { value } => Some(*value),
+            PacketContent::Operator {
+                operator,
+                subpackets,
+            } => {
+                let values = subpackets
+                    .iter()
+                    .map(Packet::value)
+                    .collect::<Option<Vec<_>>>()?;
+                let mut values = values.into_iter();
+                match operator {
+                    Sum => values.try_fold(0, u64::checked_add),
+                    Product => values.try_fold(1, u64::checked_mul),
+                    Minimum => values.min(),
+                    Maximum => values.max(),
+                    GreaterThan => Some((values.next() > values.next()) as u64),
+                    LessThan => Some((values.next() < values.next()) as u64),
+                    EqualTo => Some((values.next() == values.next()) as u64),
+                }
+            }
+        }
+    }
+}
+
+/// Shows the packet as an expression, like `sum(1, product(2, 3))`, leaving out the versions.
+impl Display for Packet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.content {
+            PacketContent::Literal { value } => write!(f, "{}", value),
+            PacketContent::Operator {
+                operator,
+                subpackets,
+            } => {
+                write!(f, "{}(", operator)?;
+                for (i, packet) in subpackets.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{}", packet)?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+fn hex_to_bin(line: &Line) -> Result<Vec<bool>, ParseError> {
+    let mut bits = Vec::with_capacity(4 * line.text.len());
+    for (i, c) in line.text.char_indices() {
+        let n = c
+            .to_digit(16)
+            .ok_or_else(|| line.error(&line.text[i..], "expected a hexadecimal digit"))?;
+        bits.extend((0..4).rev().map(|shift| n >> shift & 1 == 1));
+    }
+    Ok(bits)
+}
+
+/// Reads the bits of a transmission, which can be limited to the length of an operator's subpackets.
+struct BitReader<'a> {
+    line: Line<'a>,
+    bits: Vec<bool>,
+    pos: usize,
+    end: usize,
+}
+
+impl BitReader<'_> {
+    fn error(&self, message: &str) -> ParseError {
+        let hex_pos = (self.pos / 4).min(self.line.text.len());
+        let hex_end = (hex_pos + 1).min(self.line.text.len());
+        self.line.error(&self.line.text[hex_pos..hex_end], message)
+    }
+
+    fn parse_bin(&mut self, n: usize) -> Result<u64, ParseError> {
+        if self.pos + n > self.end {
+            return Err(self.error("expected more bits"));
+        }
+        let value = self.bits[self.pos..self.pos + n]
+            .iter()
+            .fold(0, |value, &bit| value << 1 | bit as u64);
+        self.pos += n;
+        Ok(value)
+    }
+}
+
+fn parse_packet(bits: &mut BitReader, depth: usize) -> Result<Packet, ParseError> {
+    if depth > MAX_DEPTH {
+        return Err(bits.error(&format!(
+            "expected packets nested at most {} deep",
+            MAX_DEPTH
+        )));
+    }
+    let version = bits.parse_bin(3)? as u8;
+    let id = bits.parse_bin(3)?;
+    let packet = if id == LITERAL_VALUE_ID {
+        let mut value = 0;
+        loop {
+            let next = bits.parse_bin(1)?;
+            if value >> 60 != 0 {
+                return Err(bits.error("expected a literal value that fits in 64 bits"));
+            }
+            value = value << 4 | bits.parse_bin(4)?;
+            if next == 0 {
+                break;
+            }
+        }
+        Packet::literal(version, value)
+    } else {
+        let mut subpackets = vec![];
+        if bits.parse_bin(1)? == 0 {
+            let length = bits.parse_bin(15)? as usize;
+            if bits.pos + length > bits.end {
+                return Err(bits.error("expected more bits"));
+            }
+            let outer_end = mem::replace(&mut bits.end, bits.pos + length);
+            while bits.pos < bits.end {
+                subpackets.push(parse_packet(bits, depth + 1)?);
+            }
+            bits.end = outer_end;
+        } else {
+            let num_subpackets = bits.parse_bin(11)?;
+            for _ in 0..num_subpackets {
+                subpackets.push(parse_packet(bits, depth + 1)?);
+            }
+        }
+
+        // ids only have 3 bits, and 4 is a literal value
+        let operator = Operation::from_id(id as u8).unwrap();
+        match (operator.is_comparison(), subpackets.len()) {
+            (true, 2) | (false, 1..) => {}
+            (true, _) => return Err(bits.error("expected exactly 2 subpackets")),
+            (false, _) => return Err(bits.error("expected at least 1 subpacket")),
+        }
+        Packet::operator(version, operator, subpackets)
+    };
+
+    Ok(packet)
+}
+
+pub fn parse(input: &str) -> Result<Packet, ParseError> {
+    let line = first_line(DAY, input, "expected a hexadecimal transmission")?;
+    let line = Line {
+        text: line.text.trim_end(),
+        ..line
+    };
+    let bits = hex_to_bin(&line)?;
+    let packet = parse_packet(
+        &mut BitReader {
+            line,
+            end: bits.len(),
+            bits,
+            pos: 0,
+        },
+        0,
+    )?;
+    if packet.value().is_none() {
+        return Err(line.error(line.text, "expected a value that fits in 64 bits"));
+    }
+    Ok(packet)
+}
+
+pub fn part_1(packet: &Packet) -> u32 {
+    packet.version_sum()
+}
+
+pub fn part_2(packet: &Packet) -> u64 {
+    packet.value().expect("parsing checks the value fits")
+}
+
+/// The examples from the puzzle description, with the answers it gives for them.
+pub fn examples() -> Vec<Example> {
+    vec![
+        ("8A004A801A8002F478", Some(Answer::Int(16)), None),
+        ("620080001611562C8802118E34", Some(Answer::Int(12)), None),
+        ("C0015000016115A2E0802F182340", Some(Answer::Int(23)), None),
+        (
+            "A0016C880162017C3686B18A3D4780",
+            Some(Answer::Int(31)),
+            None,
+        ),
+        ("C200B40A82", None, Some(Answer::Int(3))),
+        ("04005AC33890", None, Some(Answer::Int(54))),
+        ("880086C3E88112", None, Some(Answer::Int(7))),
+        ("CE00C43D881120", None, Some(Answer::Int(9))),
+        ("D8005AC2A8F0", None, Some(Answer::Int(1))),
+        ("F600BC2D8F", None, Some(Answer::Int(0))),
+        ("9C005AC2F8F0", None, Some(Answer::Int(0))),
+        ("9C0141080250320F1802104A08", None, Some(Answer::Int(1))),
+    ]
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = DAY;
+    type Input = Packet;
+
+    fn parse(input: &str) -> Result<Packet, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Packet) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Packet) -> Answer {
+        part_2(input).into()
+    }
+
+    fn examples() -> Vec<Example> {
+        examples()
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test() {
+        for (input, part_1_answer, part_2_answer) in examples() {
+            let packet = parse(input).unwrap();
+            if let Some(answer) = part_1_answer {
+                assert_eq!(answer, part_1(&packet).into(), "{}", input);
+            }
+            if let Some(answer) = part_2_answer {
+                assert_eq!(answer, part_2(&packet).into(), "{}", input);
+            }
+        }
+
+        // the product of two of the largest literals
+        let err = parse("260084FFFFFFFFFFFFFFFFFFEF13FFFFFFFFFFFFFFFFFFBC").unwrap_err();
+        assert_eq!((1, 1), (err.line, err.column));
+
+        // 300 sums of one subpacket each, around a literal
+        let nested = "020040801020040801".repeat(75) + "102";
+        let err = parse(&nested).unwrap_err();
+        assert_eq!((1, 1157), (err.line, err.column));
+
+        let packet = parse("9C0141080250320F1802104A08").unwrap();
+        assert_eq!("eq(sum(1, 3), product(2, 2))", packet.to_string());
+        let built = Packet::operator(
+            4,
+            EqualTo,
+            vec![
+                Packet::operator(2, Sum, vec![Packet::literal(2, 1), Packet::literal(4, 3)]),
+                Packet::operator(
+                    6,
+                    Product,
+                    vec![Packet::literal(0, 2), Packet::literal(2, 2)],
+                ),
+            ],
+        );
+        assert_eq!(packet, built);
+        assert_eq!(Some(Sum), Operation::from_id(Sum.id()));
+    }
+}
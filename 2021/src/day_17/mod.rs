@@ -0,0 +1,146 @@
+use std::ops::Range;
+
+use crate::{
+    parse::{first_line, Line},
+    Answer, Example, ParseError, Solution,
+};
+
+const DAY: u8 = 17;
+
+const START_Y: i64 = 0;
+const START_X: i64 = 0;
+
+pub struct Target {
+    x_range: Range<i64>,
+    y_range: Range<i64>,
+}
+
+impl Target {
+    fn in_range(&self, (x, y): (i64, i64)) -> bool {
+        self.x_range.contains(&x) && self.y_range.contains(&y)
+    }
+
+    fn max_y_vel(&self) -> i64 {
+        self.y_range.end.max(i64::abs(self.y_range.start) - 1)
+    }
+
+    fn lo_y_vel(&self) -> i64 {
+        -self.max_y_vel() - 1
+    }
+
+    fn lo_x_vel(&self) -> i64 {
+        0.min(self.x_range.start - START_X)
+    }
+
+    fn hi_x_vel(&self) -> i64 {
+        0.max(self.x_range.end - START_X)
+    }
+
+    fn is_hit_from(&self, (mut x_v, mut y_v): (i64, i64)) -> bool {
+        let (mut x, mut y) = (START_X, START_Y);
+        loop {
+            if self.in_range((x, y)) {
+                return true;
+            }
+            x += x_v;
+            y += y_v;
+            y_v -= 1;
+            x_v += -x_v.signum();
+
+            if y < self.y_range.start && y_v <= 0 {
+                return false;
+            }
+        }
+    }
+}
+
+/// Parses a range of `i32`s, widened so that the launches aimed at its far ends can't overflow.
+fn parse_range<'a>(line: &Line<'a>, range: &'a str, key: &str) -> Result<Range<i64>, ParseError> {
+    let inclusive = line.range::<i32>(range, key)?;
+    Ok(i64::from(*inclusive.start())..i64::from(*inclusive.end()) + 1)
+}
+
+pub fn parse(input: &str) -> Result<Target, ParseError> {
+    let line = first_line(DAY, input, "expected the target area")?;
+    let ranges = line.strip_prefix(line.text.trim_end(), "target area: ")?;
+    let (x_range, y_range) = line.split_once(ranges, ", ")?;
+
+    Ok(Target {
+        x_range: parse_range(&line, x_range, "x")?,
+        y_range: parse_range(&line, y_range, "y")?,
+    })
+}
+
+pub fn part_1(target: &Target) -> i64 {
+    // the fastest upwards launch usually hits, as long as some probe can stop over the target,
+    // but when none can in time we have to fall back to slower ones
+    let hits =
+        |y_v| (target.lo_x_vel()..=target.hi_x_vel()).any(|x_v| target.is_hit_from((x_v, y_v)));
+    let init_y_vel = (target.lo_y_vel()..=target.max_y_vel())
+        .rev()
+        .find(|&y_v| hits(y_v));
+    let init_y_vel = init_y_vel.unwrap_or(0).max(0);
+    init_y_vel * (init_y_vel + 1) / 2
+}
+
+pub fn part_2(target: &Target) -> u32 {
+    let mut count = 0;
+    for x_v in target.lo_x_vel()..=target.hi_x_vel() {
+        for y_v in target.lo_y_vel()..=target.max_y_vel() {
+            count += target.is_hit_from((x_v, y_v)) as u32;
+        }
+    }
+
+    count
+}
+
+/// The examples from the puzzle description, with the answers it gives for them.
+pub fn examples() -> Vec<Example> {
+    vec![(
+        "target area: x=20..30, y=-10..-5",
+        Some(Answer::Int(45)),
+        Some(Answer::Int(112)),
+    )]
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = DAY;
+    type Input = Target;
+
+    fn parse(input: &str) -> Result<Target, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Target) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Target) -> Answer {
+        part_2(input).into()
+    }
+
+    fn examples() -> Vec<Example> {
+        examples()
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test() {
+        let input = parse(examples()[0].0).unwrap();
+        assert_eq!(45, part_1(&input));
+        assert_eq!(112, part_2(&input));
+
+        // no probe can stop over this target, so only the ones passing straight through hit it
+        let input = parse("target area: x=16..16, y=-17..-9").unwrap();
+        assert_eq!(0, part_1(&input));
+        let input = parse("target area: x=20..2147483647, y=-2147483648..-5").unwrap();
+        assert_eq!(2147483647, input.max_y_vel());
+        assert!(!input.is_hit_from((0, input.lo_y_vel())));
+    }
+}
@@ -0,0 +1,337 @@
+use std::{fmt::Display, iter::Peekable, ops::Add, str::FromStr};
+
+use crate::{
+    parse::{end_of_input, lines, Line},
+    Answer, Example, ParseError, Solution,
+};
+
+const DAY: u8 = 18;
+/// Numbers in the input are already reduced, so nothing is nested deeper than this.
+const MAX_DEPTH: u8 = 4;
+
+/// A regular number in a snailfish number, along with how many pairs it is inside.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Entry {
+    depth: u8,
+    value: u8,
+}
+
+impl Entry {
+    pub fn new(depth: u8, value: u8) -> Entry {
+        Entry { depth, value }
+    }
+
+    pub fn depth(self) -> u8 {
+        self.depth
+    }
+
+    pub fn value(self) -> u8 {
+        self.value
+    }
+}
+
+/// A snailfish number, stored as its regular numbers from left to right.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Number(Vec<Entry>);
+
+impl Number {
+    /// Creates a number from its regular numbers, whose depths must describe a tree of pairs
+    /// like those of [`Number::entries`] do, and which must already be reduced like the ones in
+    /// the input are.
+    pub fn from_entries(entries: Vec<Entry>) -> Result<Number, String> {
+        if let Some(e) = entries.iter().find(|e| e.depth > MAX_DEPTH || e.value > 9) {
+            return Err(format!(
+                "expected a reduced number, found {} nested {} deep",
+                e.value, e.depth
+            ));
+        }
+        let mut rest = entries.iter().copied().peekable();
+        if !is_pair(&mut rest, 0) || rest.next().is_some() {
+            return Err("expected the depths to describe a tree of pairs".to_string());
+        }
+        Ok(Number(entries))
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.0
+    }
+
+    pub fn magnitude(&self) -> u32 {
+        magnitude(&self.0)
+    }
+}
+
+/// Adds two numbers and reduces the sum.
+impl Add for &Number {
+    type Output = Number;
+
+    fn add(self, other: &Number) -> Number {
+        Number(reduce(add(&self.0, &other.0)))
+    }
+}
+
+/// Writes the pair or regular number at `depth` that starts with the next entry.
+fn write_element(
+    f: &mut std::fmt::Formatter<'_>,
+    entries: &mut Peekable<impl Iterator<Item = Entry>>,
+    depth: u8,
+) -> std::fmt::Result {
+    match entries.peek() {
+        Some(e) if e.depth == depth => write!(f, "{}", entries.next().unwrap().value),
+        Some(_) => {
+            f.write_str("[")?;
+            write_element(f, entries, depth + 1)?;
+            f.write_str(",")?;
+            write_element(f, entries, depth + 1)?;
+            f.write_str("]")
+        }
+        None => Ok(()),
+    }
+}
+
+/// Shows the number as the puzzle does, like `[[1,2],3]`.
+impl Display for Number {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_element(f, &mut self.0.iter().copied().peekable(), 0)
+    }
+}
+
+impl FromStr for Number {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Number, ParseError> {
+        let mut lines = lines(DAY, s);
+        let line = lines
+            .next()
+            .ok_or_else(|| end_of_input(DAY, s, "expected a snailfish number"))?;
+        if let Some(extra) = lines.next() {
+            return Err(extra.error(extra.text, "expected only one snailfish number"));
+        }
+        parse_num(&line)
+    }
+}
+
+/// Whether the next entries make up a pair at `depth`, consuming them.
+fn is_pair(entries: &mut Peekable<impl Iterator<Item = Entry>>, depth: u8) -> bool {
+    (0..2).all(|_| match entries.peek() {
+        Some(e) if e.depth == depth + 1 => entries.next().is_some(),
+        Some(e) if e.depth > depth + 1 => is_pair(entries, depth + 1),
+        _ => false,
+    })
+}
+
+fn val(entries: &mut Peekable<impl Iterator<Item = Entry>>, depth: u8) -> u32 {
+    let first = entries.peek().unwrap();
+    if first.depth == depth {
+        entries.next().unwrap().value as u32
+    } else {
+        recursive_magnitude(entries, depth + 1)
+    }
+}
+
+fn recursive_magnitude<I>(entries: &mut Peekable<I>, depth: u8) -> u32
+where
+    I: Iterator<Item = Entry>,
+{
+    3 * val(entries, depth) + 2 * val(entries, depth)
+}
+
+fn magnitude(entries: &[Entry]) -> u32 {
+    recursive_magnitude(&mut entries.iter().copied().peekable(), 1)
+}
+
+fn add(a: &[Entry], b: &[Entry]) -> Vec<Entry> {
+    let mut out = a.to_vec();
+    out.extend_from_slice(b);
+    out.iter_mut().for_each(|e| e.depth += 1);
+    out
+}
+
+fn reduce(mut entries: Vec<Entry>) -> Vec<Entry> {
+    loop {
+        if !explode(&mut entries) && !split(&mut entries) {
+            break;
+        }
+    }
+    entries
+}
+
+fn split(entries: &mut Vec<Entry>) -> bool {
+    match entries.iter().position(|e| e.value > 9) {
+        None => false,
+        Some(p) => {
+            let old = entries[p].value;
+            entries[p].value = old / 2;
+            entries[p].depth += 1;
+            entries.insert(
+                p + 1,
+                Entry {
+                    depth: entries[p].depth,
+                    value: old.div_ceil(2),
+                },
+            );
+            true
+        }
+    }
+}
+
+fn explode(entries: &mut Vec<Entry>) -> bool {
+    match entries.iter().position(|e| e.depth > 4) {
+        Some(p) if p < entries.len() - 1 => {
+            let (left, right) = (entries[p].value, entries[p + 1].value);
+            if p > 0 {
+                entries[p - 1].value += left;
+            }
+            if p < entries.len() - 2 {
+                entries[p + 2].value += right;
+            }
+            entries[p].value = 0;
+            entries[p].depth -= 1;
+            entries.remove(p + 1);
+            true
+        }
+        _ => false,
+    }
+}
+
+fn first_char(s: &str) -> &str {
+    &s[..s.chars().next().map_or(0, char::len_utf8)]
+}
+
+/// Parses a pair or a regular number from the start of `s`, returning what's left of it.
+fn parse_element<'a>(
+    line: &Line<'a>,
+    s: &'a str,
+    depth: u8,
+    entries: &mut Vec<Entry>,
+) -> Result<&'a str, ParseError> {
+    match s.bytes().next() {
+        Some(b'[') if depth == MAX_DEPTH => Err(line.error(
+            first_char(s),
+            format!("expected pairs nested at most {} deep", MAX_DEPTH),
+        )),
+        Some(b'[') => {
+            let rest = parse_element(line, &s[1..], depth + 1, entries)?;
+            let rest = line.strip_prefix(rest, ",")?;
+            let rest = parse_element(line, rest, depth + 1, entries)?;
+            line.strip_prefix(rest, "]")
+        }
+        Some(value @ b'0'..=b'9') => {
+            entries.push(Entry {
+                depth,
+                value: value - b'0',
+            });
+            Ok(&s[1..])
+        }
+        _ => Err(line.error(first_char(s), "expected '[' or a digit")),
+    }
+}
+
+fn parse_num(line: &Line) -> Result<Number, ParseError> {
+    let mut entries = Vec::with_capacity(line.text.len() / 2);
+    line.strip_prefix(line.text, "[")?;
+    let rest = parse_element(line, line.text, 0, &mut entries)?;
+    if !rest.is_empty() {
+        return Err(line.error(rest, "expected the end of the line"));
+    }
+    Ok(Number(entries))
+}
+
+pub fn parse(input: &str) -> Result<Vec<Number>, ParseError> {
+    lines(DAY, input).map(|line| parse_num(&line)).collect()
+}
+
+pub fn part_1(nums: &[Number]) -> u32 {
+    nums.iter()
+        .cloned()
+        .reduce(|a, b| &a + &b)
+        .map_or(0, |sum| sum.magnitude())
+}
+
+pub fn part_2(nums: &[Number]) -> u32 {
+    (0..nums.len())
+        .flat_map(|i| (0..nums.len()).map(move |j| (i, j)))
+        .map(|(i, j)| (&nums[i] + &nums[j]).magnitude())
+        .max()
+        .unwrap_or(0)
+}
+
+/// The examples from the puzzle description, with the answers it gives for them.
+pub fn examples() -> Vec<Example> {
+    vec![(
+        "\
+[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+[[[5,[2,8]],4],[5,[[9,9],0]]]
+[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+[[[[5,4],[7,7]],8],[[8,3],8]]
+[[9,3],[[9,9],[6,[4,9]]]]
+[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]
+",
+        Some(Answer::Int(4140)),
+        Some(Answer::Int(3993)),
+    )]
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = DAY;
+    type Input = Vec<Number>;
+
+    fn parse(input: &str) -> Result<Vec<Number>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Vec<Number>) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Vec<Number>) -> Answer {
+        part_2(input).into()
+    }
+
+    fn examples() -> Vec<Example> {
+        examples()
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test() {
+        let num = parse("[[1,2],[[3,4],5]]").unwrap().remove(0);
+        assert_eq!(143, num.magnitude());
+        let num: Number = "[[[[5,0],[7,4]],[5,5]],[6,6]]".parse().unwrap();
+        assert_eq!(1137, num.magnitude());
+        assert_eq!("[[[[5,0],[7,4]],[5,5]],[6,6]]", num.to_string());
+        let a: Number = "[[[[4,3],4],4],[7,[[8,4],9]]]".parse().unwrap();
+        let b: Number = "[1,1]".parse().unwrap();
+        assert_eq!("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]", (&a + &b).to_string());
+        assert_eq!(Ok(a.clone()), Number::from_entries(a.entries().to_vec()));
+        for depths in [
+            &[1][..],
+            &[1, 2],
+            &[1, 1, 1],
+            &[2, 2, 1, 2],
+            &[0, 1],
+            &[1, 5, 5],
+        ] {
+            let entries = depths.iter().map(|&depth| Entry::new(depth, 1)).collect();
+            assert!(Number::from_entries(entries).is_err());
+        }
+        assert!(Number::from_entries(vec![Entry::new(1, 10), Entry::new(1, 1)]).is_err());
+
+        let input = parse(examples()[0].0).unwrap();
+        assert_eq!(4140, part_1(&input));
+        assert_eq!(3993, part_2(&input));
+
+        let err = parse("[[1,2],[3,4]\n[1,[2,x]]").unwrap_err();
+        assert_eq!((1, 13), (err.line, err.column));
+    }
+}
@@ -0,0 +1,520 @@
+use std::{
+    fmt::Display,
+    hash::Hash,
+    ops::{Add, Sub},
+    sync::OnceLock,
+};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    cancel,
+    parse::{end_of_input, lines, sections, Line},
+    Answer, Example, Param, Params, ParseError, Part, Solution,
+};
+
+const DAY: u8 = 19;
+
+/// A position or offset, as `x, y, z`.
+#[derive(Debug, Clone, Copy, PartialEq, Hash, Eq)]
+pub struct Vector(pub i16, pub i16, pub i16);
+
+impl From<(i16, i16, i16)> for Vector {
+    fn from(t: (i16, i16, i16)) -> Self {
+        Vector(t.0, t.1, t.2)
+    }
+}
+
+/// One of the axes.
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Coord {
+    X,
+    Y,
+    Z,
+}
+
+impl Coord {
+    fn apply(self, v: &Vector) -> i16 {
+        match self {
+            Coord::X => v.0,
+            Coord::Y => v.1,
+            Coord::Z => v.2,
+        }
+    }
+}
+
+/// An axis, which may be flipped.
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Sign {
+    Neg(Coord),
+    Pos(Coord),
+}
+
+impl Sign {
+    fn apply(self, v: &Vector) -> i16 {
+        match self {
+            Sign::Neg(c) => -c.apply(v),
+            Sign::Pos(c) => c.apply(v),
+        }
+    }
+}
+
+impl Display for Sign {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (sign, coord) = match self {
+            Sign::Neg(c) => ("-", c),
+            Sign::Pos(c) => ("", c),
+        };
+        let axis = match coord {
+            Coord::X => "x",
+            Coord::Y => "y",
+            Coord::Z => "z",
+        };
+        write!(f, "{}{}", sign, axis)
+    }
+}
+
+/// A way a scanner can be facing, given by which axis, possibly flipped, ends up as each of the
+/// `x`, `y` and `z` axes.
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Rotation(pub Sign, pub Sign, pub Sign);
+
+impl Rotation {
+    pub const IDENTITY: Rotation = Rotation(
+        Sign::Pos(Coord::X),
+        Sign::Pos(Coord::Y),
+        Sign::Pos(Coord::Z),
+    );
+
+    /// Every way of reordering and flipping the axes, which is all 24 rotations along with
+    /// their 24 mirror images.
+    pub fn rotations() -> impl Iterator<Item = Self> {
+        let r = Rotation;
+        let px = Sign::Pos(Coord::X);
+        let py = Sign::Pos(Coord::Y);
+        let pz = Sign::Pos(Coord::Z);
+        let nx = Sign::Neg(Coord::X);
+        let ny = Sign::Neg(Coord::Y);
+        let nz = Sign::Neg(Coord::Z);
+        [
+            r(px, py, pz),
+            r(nx, py, pz),
+            r(px, ny, pz),
+            r(px, py, nz),
+            r(nx, ny, pz),
+            r(nx, py, nz),
+            r(px, ny, nz),
+            r(nx, ny, nz),
+        ]
+        .into_iter()
+        .flat_map(move |order| {
+            let Rotation(x, y, z) = order;
+            [
+                r(x, y, z),
+                r(x, z, y),
+                r(y, x, z),
+                r(y, z, x),
+                r(z, x, y),
+                r(z, y, x),
+            ]
+            .into_iter()
+        })
+    }
+    pub fn apply(self, v: &Vector) -> Vector {
+        Vector(self.0.apply(v), self.1.apply(v), self.2.apply(v))
+    }
+}
+
+/// Shows where each axis comes from, like `(-y, x, z)`.
+impl Display for Rotation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.0, self.1, self.2)
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector(self.0 + other.0, self.1 + other.1, self.2 + other.2)
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, other: Vector) -> Vector {
+        Vector(self.0 - other.0, self.1 - other.1, self.2 - other.2)
+    }
+}
+
+/// Shows the vector as the scanner reports do, like `-618,-824,-621`.
+impl Display for Vector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},{}", self.0, self.1, self.2)
+    }
+}
+
+impl Vector {
+    pub fn manhattan_distance(&self, other: &Self) -> u32 {
+        (self.0 - other.0).unsigned_abs() as u32
+            + (self.1 - other.1).unsigned_abs() as u32
+            + (self.2 - other.2).unsigned_abs() as u32
+    }
+}
+
+#[derive(Clone)]
+struct Scan {
+    beacons: Vec<Vector>,
+}
+
+/// Every scanner's report, along with their alignment once either part has computed it.
+pub struct Scans {
+    scans: Vec<Scan>,
+    aligned: OnceLock<Alignment>,
+}
+
+/// The beacons and scanners relative to the first scanner.
+struct Alignment {
+    beacons: FxHashSet<Vector>,
+    scanners: Vec<Vector>,
+}
+
+const OVERLAP: Param = Param {
+    name: "overlap",
+    help: "how many beacons two scanners must both detect to be aligned",
+    default: 12,
+    range: 1..=100,
+};
+
+impl Scans {
+    fn aligned(&self) -> &Alignment {
+        self.aligned
+            .get_or_init(|| align(&self.scans, OVERLAP.default as usize))
+    }
+
+    /// The beacons each scanner reported, relative to itself.
+    pub fn reports(&self) -> impl Iterator<Item = &[Vector]> {
+        self.scans.iter().map(|scan| scan.beacons.as_slice())
+    }
+
+    /// Every beacon once, relative to the first scanner, in no particular order.
+    pub fn beacons(&self) -> impl ExactSizeIterator<Item = &Vector> {
+        self.aligned().beacons.iter()
+    }
+}
+
+fn parse_scan(section: &[Line]) -> Result<Scan, ParseError> {
+    let header = &section[0];
+    let id = header.strip_prefix(header.text, "--- scanner ")?;
+    let id = id
+        .strip_suffix(" ---")
+        .ok_or_else(|| header.error_at_end("expected \" ---\""))?;
+    header.parse::<u32>(id)?;
+
+    let beacons = section[1..]
+        .iter()
+        .map(|line| {
+            let [x, y, z] = line.parse_n(line.text, ",")?;
+            Ok(Vector(x, y, z))
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Scan { beacons })
+}
+
+pub fn parse(input: &str) -> Result<Scans, ParseError> {
+    let lines: Vec<_> = lines(DAY, input).collect();
+    let scans = sections(&lines)
+        .map(parse_scan)
+        .collect::<Result<Vec<_>, _>>()?;
+    if scans.is_empty() {
+        return Err(end_of_input(DAY, input, "expected a scanner"));
+    }
+    Ok(Scans {
+        scans,
+        aligned: OnceLock::new(),
+    })
+}
+
+fn do_stuff(
+    unoriented_scans: &mut Vec<Scan>,
+    unified_beacons: &mut FxHashSet<Vector>,
+    overlap: usize,
+) -> Option<Vector> {
+    let mut diffs = FxHashMap::default();
+    for (i, scan) in unoriented_scans.iter().enumerate() {
+        for r in Rotation::rotations() {
+            cancel::check();
+            diffs.clear();
+            let rotated_beacons: Vec<_> = scan.beacons.iter().map(|b| r.apply(b)).collect();
+            for ref_b in unified_beacons.iter() {
+                for other_b in &rotated_beacons {
+                    let diff = *ref_b - *other_b;
+                    let diff_count = diffs.entry(diff).or_insert(0);
+                    *diff_count += 1;
+                    if *diff_count >= overlap {
+                        let translated_rotated_beacons = &rotated_beacons
+                            .iter()
+                            .map(|&b| b + diff)
+                            .collect::<Vec<_>>();
+                        unified_beacons.extend(translated_rotated_beacons);
+                        unoriented_scans.swap_remove(i);
+                        return Some(diff);
+                    }
+                }
+            }
+        }
+    }
+    None
+}
+fn align(scans: &[Scan], overlap: usize) -> Alignment {
+    let mut unoriented_scans = scans.to_vec();
+    let mut unified_scan = FxHashSet::from_iter(unoriented_scans.swap_remove(0).beacons);
+
+    let mut diffs = vec![];
+    while !unoriented_scans.is_empty() {
+        let diff = do_stuff(&mut unoriented_scans, &mut unified_scan, overlap);
+        diffs.push(diff.unwrap_or_else(|| {
+            panic!(
+                "{} scanners don't overlap the rest by {} beacons",
+                unoriented_scans.len(),
+                overlap
+            )
+        }));
+    }
+
+    Alignment {
+        beacons: unified_scan,
+        scanners: diffs,
+    }
+}
+
+fn max_distance(alignment: &Alignment) -> u32 {
+    let scanners = &alignment.scanners;
+    let mut max = 0;
+    for a in scanners {
+        for b in scanners {
+            max = a.manhattan_distance(b).max(max);
+        }
+    }
+    max
+}
+
+pub fn part_1(scans: &Scans) -> usize {
+    scans.beacons().len()
+}
+
+pub fn part_2(scans: &Scans) -> u32 {
+    max_distance(scans.aligned())
+}
+
+/// The examples from the puzzle description, with the answers it gives for them.
+pub fn examples() -> Vec<Example> {
+    vec![(
+        "\
+--- scanner 0 ---
+404,-588,-901
+528,-643,409
+-838,591,734
+390,-675,-793
+-537,-823,-458
+-485,-357,347
+-345,-311,381
+-661,-816,-575
+-876,649,763
+-618,-824,-621
+553,345,-567
+474,580,667
+-447,-329,318
+-584,868,-557
+544,-627,-890
+564,392,-477
+455,729,728
+-892,524,684
+-689,845,-530
+423,-701,434
+7,-33,-71
+630,319,-379
+443,580,662
+-789,900,-551
+459,-707,401
+
+--- scanner 1 ---
+686,422,578
+605,423,415
+515,917,-361
+-336,658,858
+95,138,22
+-476,619,847
+-340,-569,-846
+567,-361,727
+-460,603,-452
+669,-402,600
+729,430,532
+-500,-761,534
+-322,571,750
+-466,-666,-811
+-429,-592,574
+-355,545,-477
+703,-491,-529
+-328,-685,520
+413,935,-424
+-391,539,-444
+586,-435,557
+-364,-763,-893
+807,-499,-711
+755,-354,-619
+553,889,-390
+
+--- scanner 2 ---
+649,640,665
+682,-795,504
+-784,533,-524
+-644,584,-595
+-588,-843,648
+-30,6,44
+-674,560,763
+500,723,-460
+609,671,-379
+-555,-800,653
+-675,-892,-343
+697,-426,-610
+578,704,681
+493,664,-388
+-671,-858,530
+-667,343,800
+571,-461,-707
+-138,-166,112
+-889,563,-600
+646,-828,498
+640,759,510
+-630,509,768
+-681,-892,-333
+673,-379,-804
+-742,-814,-386
+577,-820,562
+
+--- scanner 3 ---
+-589,542,597
+605,-692,669
+-500,565,-823
+-660,373,557
+-458,-679,-417
+-488,449,543
+-626,468,-788
+338,-750,-386
+528,-832,-391
+562,-778,733
+-938,-730,414
+543,643,-506
+-524,371,-870
+407,773,750
+-104,29,83
+378,-903,-323
+-778,-728,485
+426,699,580
+-438,-605,-362
+-469,-447,-387
+509,732,623
+647,635,-688
+-868,-804,481
+614,-800,639
+595,780,-596
+
+--- scanner 4 ---
+727,592,562
+-293,-554,779
+441,611,-461
+-714,465,-776
+-743,427,-804
+-660,-479,-426
+832,-632,460
+927,-485,-438
+408,393,-506
+466,436,-512
+110,16,151
+-258,-428,682
+-393,719,612
+-211,-452,876
+808,-476,-593
+-575,615,604
+-485,667,467
+-680,325,-822
+-627,-443,-432
+872,-547,-609
+833,512,582
+807,604,487
+839,-516,451
+891,-625,532
+-652,-548,-490
+30,-46,-14
+",
+        Some(Answer::Int(79)),
+        Some(Answer::Int(3621)),
+    )]
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = DAY;
+    type Input = Scans;
+    const PARAMS: &'static [Param] = &[OVERLAP];
+
+    fn parse(input: &str) -> Result<Scans, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Scans) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Scans) -> Answer {
+        part_2(input).into()
+    }
+
+    fn solve_with(input: &Scans, part: Part, params: &Params) -> Answer {
+        // only the puzzle's own overlap is cached, since other values are one-off experiments
+        let fresh;
+        let alignment = match params.get(&OVERLAP) {
+            overlap if overlap == OVERLAP.default => input.aligned(),
+            overlap => {
+                fresh = align(&input.scans, overlap as usize);
+                &fresh
+            }
+        };
+        match part {
+            Part::One => alignment.beacons.len().into(),
+            Part::Two => max_distance(alignment).into(),
+        }
+    }
+
+    fn examples() -> Vec<Example> {
+        examples()
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test() {
+        let input = parse(examples()[0].0).unwrap();
+        assert_eq!(79, part_1(&input));
+        assert_eq!(3621, part_2(&input));
+
+        let v = Vector(1, -2, 3);
+        assert_eq!(48, Rotation::rotations().count());
+        assert_eq!(v, Rotation::IDENTITY.apply(&v));
+        let r = Rotation(
+            Sign::Neg(Coord::Y),
+            Sign::Pos(Coord::X),
+            Sign::Pos(Coord::Z),
+        );
+        assert_eq!("(-y, x, z)", r.to_string());
+        assert_eq!("2,1,3", r.apply(&v).to_string());
+        assert_eq!(Vector(0, 0, 0), v - v);
+        assert_eq!(12, v.manhattan_distance(&(v + v + v)));
+    }
+}
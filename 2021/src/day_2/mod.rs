@@ -0,0 +1,106 @@
+use crate::{parse::lines, Answer, Example, ParseError, Solution};
+
+const DAY: u8 = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Forward(i64),
+    Down(i64),
+    Up(i64),
+}
+
+pub fn part_1(commands: &[Command]) -> i64 {
+    let (hor, depth) = commands
+        .iter()
+        .fold((0, 0), |(hor, depth), command| match *command {
+            Command::Forward(num) => (hor + num, depth),
+            Command::Down(num) => (hor, depth + num),
+            Command::Up(num) => (hor, depth - num),
+        });
+
+    hor * depth
+}
+
+pub fn part_2(commands: &[Command]) -> i64 {
+    let (hor, depth, _aim) = commands
+        .iter()
+        .fold((0, 0, 0), |(hor, depth, aim), command| match *command {
+            Command::Forward(num) => (hor + num, depth + aim * num, aim),
+            Command::Down(num) => (hor, depth, aim + num),
+            Command::Up(num) => (hor, depth, aim - num),
+        });
+
+    hor * depth
+}
+
+pub fn parse(input: &str) -> Result<Vec<Command>, ParseError> {
+    lines(DAY, input)
+        .map(|line| {
+            let (word, num) = line.split_once(line.text, " ")?;
+            let num = line.parse(num)?;
+            match word {
+                "forward" => Ok(Command::Forward(num)),
+                "down" => Ok(Command::Down(num)),
+                "up" => Ok(Command::Up(num)),
+                _ => Err(line.error(word, "expected 'forward', 'down' or 'up'")),
+            }
+        })
+        .collect()
+}
+
+/// The examples from the puzzle description, with the answers it gives for them.
+pub fn examples() -> Vec<Example> {
+    vec![(
+        "\
+forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2",
+        Some(Answer::Int(150)),
+        Some(Answer::Int(900)),
+    )]
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = DAY;
+    type Input = Vec<Command>;
+
+    fn parse(input: &str) -> Result<Vec<Command>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Vec<Command>) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Vec<Command>) -> Answer {
+        part_2(input).into()
+    }
+
+    fn examples() -> Vec<Example> {
+        examples()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = parse(examples()[0].0).unwrap();
+
+        assert_eq!(part_1(&input), 150);
+        assert_eq!(part_2(&input), 900);
+
+        let err = parse("forward 5\nbackward 2").unwrap_err();
+        assert_eq!(
+            (2, 1, "backward"),
+            (err.line, err.column, err.text.as_str())
+        );
+    }
+}
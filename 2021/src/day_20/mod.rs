@@ -0,0 +1,232 @@
+use std::{fmt::Display, mem};
+
+use crate::{
+    grid::{self, Grid, Point},
+    parse::{end_of_input, first_line, lines, Line},
+    trace::{self, Observer, Snapshot},
+    Answer, Example, Param, Params, ParseError, Part, Solution,
+};
+
+const DAY: u8 = 20;
+
+pub struct Puzzle {
+    lookup: Vec<bool>,
+    image: Grid<bool>,
+}
+
+fn parse_pixels(line: &Line) -> Result<Vec<bool>, ParseError> {
+    line.text
+        .char_indices()
+        .map(|(i, c)| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(line.error(&line.text[i..i + c.len_utf8()], "expected '#' or '.'")),
+        })
+        .collect()
+}
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    let first = first_line(DAY, input, "expected the enhancement algorithm")?;
+    let lookup = parse_pixels(&first)?;
+    if lookup.len() != 512 {
+        return Err(first.error(first.text, "expected 512 pixels"));
+    }
+
+    let image = grid::from_lines(
+        lines(DAY, input)
+            .skip(1)
+            .filter(|line| !line.text.is_empty()),
+        |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        },
+        "'#' or '.'",
+    )?
+    .ok_or_else(|| end_of_input(DAY, input, "expected an image"))?;
+
+    Ok(Puzzle { lookup, image })
+}
+
+struct Image {
+    lookup: Vec<bool>,
+    grid: Grid<bool>,
+    on_at_inf: bool,
+}
+
+fn bits_to_u16<I: Iterator<Item = usize>>(iter: I) -> usize {
+    iter.fold(0, |result, bit| (result << 1) ^ bit)
+}
+
+impl Image {
+    fn new(puzzle: &Puzzle, num_iter: u8) -> Image {
+        let lookup = puzzle.lookup.clone();
+
+        let buff = (num_iter + 1) as usize;
+        let image = &puzzle.image;
+        let grid = Grid::from_fn(
+            image.width() + 2 * buff,
+            image.height() + 2 * buff,
+            |(x, y)| x >= buff && y >= buff && image.get((x - buff, y - buff)) == Some(&true),
+        );
+
+        Image {
+            lookup,
+            grid,
+            on_at_inf: false,
+        }
+    }
+
+    #[inline]
+    fn get(&self, p: Option<Point>) -> bool {
+        match p {
+            Some(p) => self.grid[p],
+            None => self.on_at_inf && self.lookup[0],
+        }
+    }
+
+    fn enhance_grid_at(&self, p: Point) -> bool {
+        let index: usize = bits_to_u16(
+            [
+                (-1, -1),
+                (0, -1),
+                (1, -1),
+                (-1, 0),
+                (0, 0),
+                (1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+            ]
+            .iter()
+            .map(|&d| self.get(self.grid.offset(p, d)) as usize),
+        );
+
+        self.lookup[index]
+    }
+
+    fn enhance(&mut self, num_iter: u8, observer: &mut dyn Observer) {
+        let mut other = self.grid.clone();
+        trace::show(observer, || self.snapshot(0));
+        for step in 1..=num_iter as usize {
+            for p in self.grid.points() {
+                other[p] = self.enhance_grid_at(p);
+            }
+            mem::swap(&mut self.grid, &mut other);
+            self.on_at_inf = !self.on_at_inf;
+            trace::show(observer, || self.snapshot(step));
+        }
+    }
+
+    fn snapshot(&self, step: usize) -> Snapshot {
+        Snapshot::new(step, self.to_string(), 2, |c| (c == '#') as u8)
+    }
+
+    fn count_lit(&self) -> usize {
+        self.grid.iter().filter(|b| **b).count()
+    }
+}
+
+impl Display for Image {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let pixels = self.grid.map(|&b| if b { '#' } else { '.' });
+        write!(f, "{}", pixels)
+    }
+}
+
+fn solve(puzzle: &Puzzle, num_iter: u8, observer: &mut dyn Observer) -> usize {
+    let mut image = Image::new(puzzle, num_iter);
+    image.enhance(num_iter, observer);
+    image.count_lit()
+}
+
+const PART_1_ENHANCEMENTS: Param = Param {
+    name: "part1_enhancements",
+    help: "how many times part 1 enhances the image",
+    default: 2,
+    range: 0..=200,
+};
+const PART_2_ENHANCEMENTS: Param = Param {
+    name: "part2_enhancements",
+    help: "how many times part 2 enhances the image",
+    default: 50,
+    range: 0..=200,
+};
+
+pub fn part_1(puzzle: &Puzzle) -> usize {
+    solve(puzzle, PART_1_ENHANCEMENTS.default as u8, &mut ())
+}
+
+pub fn part_2(puzzle: &Puzzle) -> usize {
+    solve(puzzle, PART_2_ENHANCEMENTS.default as u8, &mut ())
+}
+
+/// The examples from the puzzle description, with the answers it gives for them.
+pub fn examples() -> Vec<Example> {
+    vec![("..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#
+
+#..#.
+#....
+##..#
+..#..
+..###", Some(Answer::Int(35)), Some(Answer::Int(3351)))]
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = DAY;
+    type Input = Puzzle;
+    const PARAMS: &'static [Param] = &[PART_1_ENHANCEMENTS, PART_2_ENHANCEMENTS];
+
+    fn parse(input: &str) -> Result<Puzzle, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Puzzle) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Puzzle) -> Answer {
+        part_2(input).into()
+    }
+
+    fn solve_with(input: &Puzzle, part: Part, params: &Params) -> Answer {
+        Self::trace(input, part, params, &mut ()).unwrap()
+    }
+
+    fn trace(
+        input: &Puzzle,
+        part: Part,
+        params: &Params,
+        observer: &mut dyn Observer,
+    ) -> Option<Answer> {
+        let num_iter = match part {
+            Part::One => params.get(&PART_1_ENHANCEMENTS),
+            Part::Two => params.get(&PART_2_ENHANCEMENTS),
+        };
+        Some(solve(input, num_iter as u8, observer).into())
+    }
+
+    fn examples() -> Vec<Example> {
+        examples()
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test() {
+        let input = parse(examples()[0].0).unwrap();
+        assert_eq!(35, part_1(&input));
+        assert_eq!(3351, part_2(&input));
+
+        let mut snapshots = vec![];
+        assert_eq!(35, solve(&input, 2, &mut snapshots));
+        assert_eq!(3, snapshots.len());
+        let lit = |s: &Snapshot| s.image.iter().filter(|&&shade| shade == 1).count();
+        assert_eq!((10, 35), (lit(&snapshots[0]), lit(&snapshots[2])));
+    }
+}
@@ -0,0 +1,250 @@
+use std::mem;
+
+use crate::{
+    parse::{end_of_input, lines},
+    Answer, Example, Param, Params, ParseError, Part, Solution,
+};
+
+const DAY: u8 = 21;
+
+const BOARD_LEN: usize = 10;
+
+#[derive(Clone, PartialEq, Eq, Hash, Copy)]
+pub struct Player {
+    pos: usize,
+    score: usize,
+}
+
+impl Player {
+    fn new(pos: usize) -> Player {
+        Player { pos, score: 0 }
+    }
+    fn advance(&mut self, n: usize) {
+        // -1 then +1 handles 1-based board indexing
+        self.pos = (self.pos + n - 1) % BOARD_LEN + 1;
+        self.score += self.pos;
+    }
+}
+
+struct DeterministicDie {
+    n: usize,
+    max: usize,
+}
+
+impl Iterator for DeterministicDie {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.n + 1;
+        self.n = next % self.max;
+        Some(next)
+    }
+}
+
+pub fn parse(input: &str) -> Result<(Player, Player), ParseError> {
+    let mut lines = lines(DAY, input);
+    let mut players = [Player::new(1); 2];
+    for (i, player) in players.iter_mut().enumerate() {
+        let line = lines
+            .next()
+            .ok_or_else(|| end_of_input(DAY, input, format!("expected player {}", i + 1)))?;
+        let prefix = format!("Player {} starting position: ", i + 1);
+        let pos = line.strip_prefix(line.text, &prefix)?;
+        *player = match line.parse(pos)? {
+            pos @ 1..=BOARD_LEN => Player::new(pos),
+            _ => return Err(line.error(pos, format!("expected a position 1-{}", BOARD_LEN))),
+        };
+    }
+    if let Some(line) = lines.find(|line| !line.text.trim().is_empty()) {
+        return Err(line.error(line.text, "expected only two players"));
+    }
+    Ok((players[0], players[1]))
+}
+
+const PART_1_TARGET: Param = Param {
+    name: "part1_target",
+    help: "the score that wins part 1's game",
+    default: 1000,
+    range: 1..=1_000_000,
+};
+// the number of universes grows so fast that counting them overflows past 27
+const PART_2_TARGET: Param = Param {
+    name: "part2_target",
+    help: "the score that wins part 2's game",
+    default: 21,
+    range: 1..=27,
+};
+
+fn score_part_1((mut p1, mut p2): (Player, Player), winning_score: usize) -> usize {
+    const ROLLS_PER_TURN: usize = 3;
+
+    let die = &mut DeterministicDie { n: 0, max: 100 };
+    let mut roll_num = 0;
+    loop {
+        let roll_total: usize = die.take(ROLLS_PER_TURN).sum();
+        roll_num += ROLLS_PER_TURN;
+        p1.advance(roll_total);
+        if p1.score >= winning_score {
+            return p2.score * roll_num;
+        }
+        mem::swap(&mut p1, &mut p2);
+    }
+}
+
+struct UniverseCounts {
+    num_won_by_turn: Vec<usize>,
+    num_not_won_by_turn: Vec<usize>,
+}
+
+fn universe_counts(player: Player, winning_score: usize) -> UniverseCounts {
+    // this holds frequencies indexed by roll totals (less the offset)
+    const ROLL_COUNTS: [usize; 7] = [1, 3, 6, 7, 6, 3, 1];
+    const ROLL_COUNT_OFFSET: usize = 2;
+    // every turn scores at least 1, so no game outlasts this many turns (counting the start)
+    let max_game_len = winning_score + 1;
+
+    // the idea here is to maintain a grid of scores tallies for each position at each turn,
+    // and build it from start to finish in a dynamic programming style.
+    // The key insight is that for each position and score on a turn, you can iterate over the potential rolls:
+    // next_turn[next_pos][next_score] += frequency(roll) * old_turn[old_pos][old_score]
+    let mut scores = vec![vec![vec![0; winning_score + 1]; BOARD_LEN + 1]; max_game_len];
+    scores[0][player.pos][0] = 1;
+    (1..max_game_len).for_each(|turn| {
+        (1..BOARD_LEN + 1).for_each(|pos| {
+            (0..winning_score).for_each(|score| {
+                ROLL_COUNTS.iter().enumerate().for_each(|(roll, freq)| {
+                    let new_pos = (pos + roll + ROLL_COUNT_OFFSET) % BOARD_LEN + 1;
+                    let new_score = (score + new_pos).min(winning_score);
+                    scores[turn][new_pos][new_score] += freq * scores[turn - 1][pos][score];
+                })
+            })
+        })
+    });
+
+    let mut result = UniverseCounts {
+        num_not_won_by_turn: vec![0; max_game_len],
+        num_won_by_turn: vec![0; max_game_len],
+    };
+    scores.iter().enumerate().for_each(|(turn, turn_data)| {
+        turn_data[1..].iter().for_each(|pos_scores| {
+            result.num_not_won_by_turn[turn] +=
+                pos_scores.iter().take(winning_score).sum::<usize>();
+            result.num_won_by_turn[turn] += pos_scores[winning_score];
+        })
+    });
+    result
+}
+
+fn sum_product<I, T>(a: I, b: T) -> usize
+where
+    T: Iterator<Item = usize>,
+    I: Iterator<Item = usize>,
+{
+    a.zip(b).fold(0, |sum, (x, y)| sum + x * y)
+}
+
+fn score_part_2((p1, p2): (Player, Player), winning_score: usize) -> usize {
+    let (p1, p2) = (
+        universe_counts(p1, winning_score),
+        universe_counts(p2, winning_score),
+    );
+
+    // In order to get the number of 'universes' where a player won at a given turn
+    // we need to multiply the not_won counts for the loser by the won counts for the winner,
+    // since all the rolls are independent events.
+    // We sum this product to get the total 'universe' count.
+
+    // p1 wins one turn ahead of p2 since they start first
+    let p1_winning_total = sum_product(
+        p1.num_won_by_turn.into_iter().skip(1),
+        p2.num_not_won_by_turn.into_iter(),
+    );
+    // p2 wins on the same turn as p1, in the universes where p1 hasn't already won
+    let p2_winning_total = sum_product(
+        p2.num_won_by_turn.into_iter(),
+        p1.num_not_won_by_turn.into_iter(),
+    );
+
+    p1_winning_total.max(p2_winning_total)
+}
+
+pub fn part_1(players: &(Player, Player)) -> usize {
+    score_part_1(*players, PART_1_TARGET.default as usize)
+}
+
+pub fn part_2(players: &(Player, Player)) -> usize {
+    score_part_2(*players, PART_2_TARGET.default as usize)
+}
+
+/// The examples from the puzzle description, with the answers it gives for them.
+pub fn examples() -> Vec<Example> {
+    vec![(
+        "\
+Player 1 starting position: 4
+Player 2 starting position: 8
+",
+        Some(Answer::Int(739785)),
+        Some(Answer::Int(444356092776315)),
+    )]
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = DAY;
+    type Input = (Player, Player);
+    const PARAMS: &'static [Param] = &[PART_1_TARGET, PART_2_TARGET];
+
+    fn parse(input: &str) -> Result<(Player, Player), ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &(Player, Player)) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &(Player, Player)) -> Answer {
+        part_2(input).into()
+    }
+
+    fn solve_with(input: &(Player, Player), part: Part, params: &Params) -> Answer {
+        match part {
+            Part::One => score_part_1(*input, params.get_usize(&PART_1_TARGET)).into(),
+            Part::Two => score_part_2(*input, params.get_usize(&PART_2_TARGET)).into(),
+        }
+    }
+
+    fn examples() -> Vec<Example> {
+        examples()
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test() {
+        let input = parse(examples()[0].0).unwrap();
+        assert_eq!(739785, part_1(&input));
+        assert_eq!(444356092776315, part_2(&input));
+
+        // here player 2 wins in more universes
+        let input = parse(
+            "\
+Player 1 starting position: 1
+Player 2 starting position: 3
+",
+        )
+        .unwrap();
+        assert_eq!(48868319769358, part_2(&input));
+
+        // the highest target still counts the universes without overflowing
+        let mut params = Params::default();
+        params.set(Day21::PARAMS, "part2_target=27").unwrap();
+        let Answer::Int(wins) = Day21::solve_with(&input, Part::Two, &params) else {
+            panic!("expected a number of universes")
+        };
+        assert!(wins > 48868319769358);
+    }
+}
@@ -0,0 +1,426 @@
+use std::{fmt::Display, ops, sync::OnceLock};
+
+use crate::{
+    parse::{lines, Line},
+    Answer, Example, Param, Params, ParseError, Part, Solution,
+};
+
+const DAY: u8 = 22;
+
+type Range = (i32, i32);
+
+/// A box of cubes, which may be empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cuboid {
+    x: Range,
+    y: Range,
+    z: Range,
+}
+
+fn intersect(a: Range, b: Range) -> Range {
+    (a.0.max(b.0), a.1.min(b.1))
+}
+
+fn is_non_empty(a: Range) -> bool {
+    a.0 < a.1
+}
+
+impl Cuboid {
+    /// Creates a cuboid from the coordinates it spans along each axis, excluding the ends.
+    pub fn new(x: ops::Range<i32>, y: ops::Range<i32>, z: ops::Range<i32>) -> Cuboid {
+        Cuboid {
+            x: (x.start, x.end),
+            y: (y.start, y.end),
+            z: (z.start, z.end),
+        }
+    }
+
+    pub fn empty() -> Cuboid {
+        Cuboid {
+            x: (0, -1),
+            y: (0, -1),
+            z: (0, -1),
+        }
+    }
+
+    pub fn x(&self) -> ops::Range<i32> {
+        self.x.0..self.x.1
+    }
+
+    pub fn y(&self) -> ops::Range<i32> {
+        self.y.0..self.y.1
+    }
+
+    pub fn z(&self) -> ops::Range<i32> {
+        self.z.0..self.z.1
+    }
+
+    /// The number of cubes inside, which is 0 if the cuboid is empty.
+    ///
+    /// A side can be nearly 2^32 long, so the widths are worked out in an `i64` and the volume,
+    /// of up to 2^96, in a `u128`.
+    pub fn size(&self) -> u128 {
+        if self.is_empty() {
+            return 0;
+        }
+        let width = |(start, end): Range| (end as i64 - start as i64) as u128;
+        width(self.x) * width(self.y) * width(self.z)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        !(is_non_empty(self.x) && is_non_empty(self.y) && is_non_empty(self.z))
+    }
+
+    /// Whether any cube is in both cuboids.
+    pub fn intersects(&self, other: &Cuboid) -> bool {
+        is_non_empty(intersect(self.x, other.x))
+            && is_non_empty(intersect(self.y, other.y))
+            && is_non_empty(intersect(self.z, other.z))
+    }
+
+    /// The cubes in both cuboids, which may be empty.
+    pub fn intersection(&self, other: &Cuboid) -> Cuboid {
+        Cuboid {
+            x: intersect(self.x, other.x),
+            y: intersect(self.y, other.y),
+            z: intersect(self.z, other.z),
+        }
+    }
+
+    /// Splits the cuboid into its intersection with `other` and up to 6 disjoint cuboids
+    /// covering the rest of it.
+    pub fn subtract(&self, other: &Cuboid) -> (Cuboid, Vec<Cuboid>) {
+        let i = self.intersection(other);
+        let remains = [
+            Cuboid {
+                x: (self.x.0, i.x.0),
+                y: self.y,
+                z: self.z,
+            },
+            Cuboid {
+                x: (i.x.1, self.x.1),
+                y: self.y,
+                z: self.z,
+            },
+            Cuboid {
+                x: (i.x.0, i.x.1),
+                y: (self.y.0, i.y.0),
+                z: self.z,
+            },
+            Cuboid {
+                x: (i.x.0, i.x.1),
+                y: (i.y.1, self.y.1),
+                z: self.z,
+            },
+            Cuboid {
+                x: i.x,
+                y: i.y,
+                z: (self.z.0, i.z.0),
+            },
+            Cuboid {
+                x: i.x,
+                y: i.y,
+                z: (i.z.1, self.z.1),
+            },
+        ]
+        .into_iter()
+        .filter(|c| !c.is_empty())
+        .collect();
+
+        (i, remains)
+    }
+}
+
+/// Shows the cuboid like the reboot steps do, with inclusive ranges like `x=10..12`.
+impl Display for Cuboid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "x={}..{},y={}..{},z={}..{}",
+            self.x.0,
+            self.x.1 - 1,
+            self.y.0,
+            self.y.1 - 1,
+            self.z.0,
+            self.z.1 - 1
+        )
+    }
+}
+
+#[derive(Debug)]
+struct Command {
+    is_on: bool,
+    cuboid: Cuboid,
+}
+
+fn parse_range<'a>(line: &Line<'a>, s: &'a str, axis: &str) -> Result<Range, ParseError> {
+    let range = line.range::<i32>(s, axis)?;
+    // the cuboids exclude their end, which has to fit in an i32 too
+    let end = range
+        .end()
+        .checked_add(1)
+        .ok_or_else(|| line.error(s, format!("expected the range to end before {}", i32::MAX)))?;
+    Ok((*range.start(), end))
+}
+
+fn parse_command(line: Line) -> Result<Command, ParseError> {
+    let (action, ranges) = line.split_once(line.text, " ")?;
+    let is_on = match action {
+        "on" => true,
+        "off" => false,
+        _ => return Err(line.error(action, "expected \"on\" or \"off\"")),
+    };
+
+    let [x, y, z] = line.split_n(ranges, ",")?;
+    Ok(Command {
+        is_on,
+        cuboid: Cuboid {
+            x: parse_range(&line, x, "x")?,
+            y: parse_range(&line, y, "y")?,
+            z: parse_range(&line, z, "z")?,
+        },
+    })
+}
+
+/// The reboot steps, along with the final reactor state once either part has computed it.
+#[derive(Debug)]
+pub struct Reboot {
+    commands: Vec<Command>,
+    reactor: OnceLock<Vec<Cuboid>>,
+}
+
+impl Reboot {
+    /// Disjoint cuboids covering exactly the cubes left on after every step.
+    pub fn reactor(&self) -> &[Cuboid] {
+        self.reactor.get_or_init(|| process(&self.commands))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Reboot, ParseError> {
+    let commands = lines(DAY, input)
+        .filter(|line| !line.text.is_empty())
+        .map(parse_command)
+        .collect::<Result<_, _>>()?;
+    Ok(Reboot {
+        commands,
+        reactor: OnceLock::new(),
+    })
+}
+
+/// Returns disjoint cuboids covering exactly the cubes left on after every command.
+fn process(commands: &[Command]) -> Vec<Cuboid> {
+    let mut on_cuboids = Vec::<Cuboid>::new();
+    for command in commands {
+        let mut new = vec![];
+        for on_cuboid in on_cuboids
+            .iter_mut()
+            .filter(|c| c.intersects(&command.cuboid))
+        {
+            let (_, remaining) = on_cuboid.subtract(&command.cuboid);
+            new.extend(remaining);
+            *on_cuboid = Cuboid::empty();
+        }
+        on_cuboids.append(&mut new);
+        if command.is_on {
+            on_cuboids.push(command.cuboid.clone());
+        }
+    }
+    on_cuboids.retain(|c| !c.is_empty());
+    on_cuboids
+}
+
+const REGION: Param = Param {
+    name: "region",
+    help: "how far from the origin along each axis part 1 counts the cubes",
+    default: 50,
+    range: 0..=1_000_000_000,
+};
+
+fn count_in_region(reboot: &Reboot, radius: i64) -> u128 {
+    let radius = radius as i32;
+    let final_region = Cuboid::new(
+        -radius..radius + 1,
+        -radius..radius + 1,
+        -radius..radius + 1,
+    );
+
+    reboot
+        .reactor()
+        .iter()
+        .map(|c| c.intersection(&final_region).size())
+        .sum()
+}
+
+pub fn part_1(reboot: &Reboot) -> u128 {
+    count_in_region(reboot, REGION.default)
+}
+
+/// The cuboids are disjoint, so even with every cube on, this can't be more than 2^96.
+pub fn part_2(reboot: &Reboot) -> u128 {
+    reboot.reactor().iter().map(Cuboid::size).sum()
+}
+
+/// The examples from the puzzle description, with the answers it gives for them.
+pub fn examples() -> Vec<Example> {
+    vec![
+        (
+            "\
+on x=-20..26,y=-36..17,z=-47..7
+on x=-20..33,y=-21..23,z=-26..28
+on x=-22..28,y=-29..23,z=-38..16
+on x=-46..7,y=-6..46,z=-50..-1
+on x=-49..1,y=-3..46,z=-24..28
+on x=2..47,y=-22..22,z=-23..27
+on x=-27..23,y=-28..26,z=-21..29
+on x=-39..5,y=-6..47,z=-3..44
+on x=-30..21,y=-8..43,z=-13..34
+on x=-22..26,y=-27..20,z=-29..19
+off x=-48..-32,y=26..41,z=-47..-37
+on x=-12..35,y=6..50,z=-50..-2
+off x=-48..-32,y=-32..-16,z=-15..-5
+on x=-18..26,y=-33..15,z=-7..46
+off x=-40..-22,y=-38..-28,z=23..41
+on x=-16..35,y=-41..10,z=-47..6
+off x=-32..-23,y=11..30,z=-14..3
+on x=-49..-5,y=-3..45,z=-29..18
+off x=18..30,y=-20..-8,z=-3..13
+on x=-41..9,y=-7..43,z=-33..15
+on x=-54112..-39298,y=-85059..-49293,z=-27449..7877
+on x=967..23432,y=45373..81175,z=27513..53682
+",
+            Some(Answer::Int(590784)),
+            None,
+        ),
+        (
+            "\
+on x=-5..47,y=-31..22,z=-19..33
+on x=-44..5,y=-27..21,z=-14..35
+on x=-49..-1,y=-11..42,z=-10..38
+on x=-20..34,y=-40..6,z=-44..1
+off x=26..39,y=40..50,z=-2..11
+on x=-41..5,y=-41..6,z=-36..8
+off x=-43..-33,y=-45..-28,z=7..25
+on x=-33..15,y=-32..19,z=-34..11
+off x=35..47,y=-46..-34,z=-11..5
+on x=-14..36,y=-6..44,z=-16..29
+on x=-57795..-6158,y=29564..72030,z=20435..90618
+on x=36731..105352,y=-21140..28532,z=16094..90401
+on x=30999..107136,y=-53464..15513,z=8553..71215
+on x=13528..83982,y=-99403..-27377,z=-24141..23996
+on x=-72682..-12347,y=18159..111354,z=7391..80950
+on x=-1060..80757,y=-65301..-20884,z=-103788..-16709
+on x=-83015..-9461,y=-72160..-8347,z=-81239..-26856
+on x=-52752..22273,y=-49450..9096,z=54442..119054
+on x=-29982..40483,y=-108474..-28371,z=-24328..38471
+on x=-4958..62750,y=40422..118853,z=-7672..65583
+on x=55694..108686,y=-43367..46958,z=-26781..48729
+on x=-98497..-18186,y=-63569..3412,z=1232..88485
+on x=-726..56291,y=-62629..13224,z=18033..85226
+on x=-110886..-34664,y=-81338..-8658,z=8914..63723
+on x=-55829..24974,y=-16897..54165,z=-121762..-28058
+on x=-65152..-11147,y=22489..91432,z=-58782..1780
+on x=-120100..-32970,y=-46592..27473,z=-11695..61039
+on x=-18631..37533,y=-124565..-50804,z=-35667..28308
+on x=-57817..18248,y=49321..117703,z=5745..55881
+on x=14781..98692,y=-1341..70827,z=15753..70151
+on x=-34419..55919,y=-19626..40991,z=39015..114138
+on x=-60785..11593,y=-56135..2999,z=-95368..-26915
+on x=-32178..58085,y=17647..101866,z=-91405..-8878
+on x=-53655..12091,y=50097..105568,z=-75335..-4862
+on x=-111166..-40997,y=-71714..2688,z=5609..50954
+on x=-16602..70118,y=-98693..-44401,z=5197..76897
+on x=16383..101554,y=4615..83635,z=-44907..18747
+off x=-95822..-15171,y=-19987..48940,z=10804..104439
+on x=-89813..-14614,y=16069..88491,z=-3297..45228
+on x=41075..99376,y=-20427..49978,z=-52012..13762
+on x=-21330..50085,y=-17944..62733,z=-112280..-30197
+on x=-16478..35915,y=36008..118594,z=-7885..47086
+off x=-98156..-27851,y=-49952..43171,z=-99005..-8456
+off x=2032..69770,y=-71013..4824,z=7471..94418
+on x=43670..120875,y=-42068..12382,z=-24787..38892
+off x=37514..111226,y=-45862..25743,z=-16714..54663
+off x=25699..97951,y=-30668..59918,z=-15349..69697
+off x=-44271..17935,y=-9516..60759,z=49131..112598
+on x=-61695..-5813,y=40978..94975,z=8655..80240
+off x=-101086..-9439,y=-7088..67543,z=33935..83858
+off x=18020..114017,y=-48931..32606,z=21474..89843
+off x=-77139..10506,y=-89994..-18797,z=-80..59318
+off x=8476..79288,y=-75520..11602,z=-96624..-24783
+on x=-47488..-1262,y=24338..100707,z=16292..72967
+off x=-84341..13987,y=2429..92914,z=-90671..-1318
+off x=-37810..49457,y=-71013..-7894,z=-105357..-13188
+off x=-27365..46395,y=31009..98017,z=15428..76570
+off x=-70369..-16548,y=22648..78696,z=-1892..86821
+on x=-53470..21291,y=-120233..-33476,z=-44150..38147
+off x=-93533..-4276,y=-16170..68771,z=-104985..-24507
+",
+            Some(Answer::Int(474140)),
+            Some(Answer::Int(2758514936282235)),
+        ),
+    ]
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = DAY;
+    type Input = Reboot;
+    const PARAMS: &'static [Param] = &[REGION];
+
+    fn parse(input: &str) -> Result<Reboot, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Reboot) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Reboot) -> Answer {
+        part_2(input).into()
+    }
+
+    fn solve_with(input: &Reboot, part: Part, params: &Params) -> Answer {
+        match part {
+            Part::One => count_in_region(input, params.get(&REGION)).into(),
+            Part::Two => part_2(input).into(),
+        }
+    }
+
+    fn examples() -> Vec<Example> {
+        examples()
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test() {
+        let input = parse(examples()[0].0).unwrap();
+        assert_eq!(590784, part_1(&input));
+
+        let input_part_2 = parse(examples()[1].0).unwrap();
+        assert_eq!(2758514936282235, part_2(&input_part_2));
+
+        let err = parse("on x=1..2,y=3..4,z=6..5").unwrap_err();
+        assert_eq!((1, 20), (err.line, err.column));
+        // too wide for an i32, and too big a volume and a total for a u64
+        let wide = parse("on x=-2000000000..2000000000,y=0..0,z=0..0").unwrap();
+        assert_eq!(4000000001, part_2(&wide));
+        let huge = parse("on x=-2000000..2000000,y=-2000000..2000000,z=-2000000..2000000").unwrap();
+        assert_eq!(4000001u128.pow(3), part_2(&huge));
+        let err = parse("on x=0..2147483647,y=3..4,z=5..6").unwrap_err();
+        assert_eq!((1, 4), (err.line, err.column));
+
+        let a = Cuboid::new(10..13, 10..13, 10..13);
+        let b = Cuboid::new(11..14, 11..14, 11..14);
+        assert_eq!("x=10..12,y=10..12,z=10..12", a.to_string());
+        let (both, rest) = a.subtract(&b);
+        assert_eq!(Cuboid::new(11..13, 11..13, 11..13), both);
+        assert_eq!(27 - 8, rest.iter().map(Cuboid::size).sum::<u128>());
+        assert!(a.intersection(&Cuboid::new(0..10, 0..10, 0..10)).is_empty());
+        assert_eq!(0, Cuboid::empty().size());
+        assert_eq!(0, Cuboid::new(0..10, 5..5, 0..10).size());
+    }
+}
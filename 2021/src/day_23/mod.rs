@@ -0,0 +1,502 @@
+use std::{cmp::Reverse, collections::BinaryHeap, fmt::Display, mem};
+
+use itertools::Itertools;
+use rustc_hash::FxHashMap;
+
+use crate::{
+    cancel,
+    parse::{end_of_input, lines},
+    trace::{Observer, Snapshot},
+    Answer, Example, Params, ParseError, Part, Solution,
+};
+
+const DAY: u8 = 23;
+
+const NUM_PODS: usize = 4;
+const HALL_LEN: usize = 11;
+const HALL_END_BUFF: usize = 2;
+const POD_GAP: usize = 2;
+
+const EMPTY: u8 = 0;
+
+fn abs_diff<T: std::ops::Sub<Output = T> + Ord>(x: T, y: T) -> T {
+    if x < y {
+        y - x
+    } else {
+        x - y
+    }
+}
+
+/// An arrangement of the amphipods, with `POD_LEN` of them fitting in each side room, along with
+/// the energy spent reaching it.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Graph<const POD_LEN: usize> {
+    pods: [[u8; POD_LEN]; NUM_PODS],
+    hallway: [u8; HALL_LEN],
+    cost: u64,
+}
+
+fn hallway_pos_for_pod(pod: usize) -> usize {
+    HALL_END_BUFF + pod * POD_GAP
+}
+
+fn pos_not_in_front_of_pod(pos: usize) -> bool {
+    !(HALL_END_BUFF..HALL_LEN - HALL_END_BUFF).contains(&pos)
+        || !(pos - HALL_END_BUFF).is_multiple_of(POD_GAP)
+}
+
+fn cost_per_step_by_idx(i: usize) -> u64 {
+    [1, 10, 100, 1000][i]
+}
+
+fn cost_per_step(b: u8) -> u64 {
+    cost_per_step_by_idx(target_pod_idx(b))
+}
+
+fn target_pod_idx(b: u8) -> usize {
+    (b - b'A') as usize
+}
+
+impl<const POD_LEN: usize> Graph<POD_LEN> {
+    /// Whether every amphipod is in its own side room.
+    pub fn is_solved(&self) -> bool {
+        (b'A'..=b'D')
+            .zip(self.pods.iter())
+            .all(|(b, pod)| pod.iter().all(|&o| b == o))
+    }
+
+    // assuming the pods can move straight through one another, giving an admissible heuristic
+    fn heuristic(&self) -> u64 {
+        let mut move_into_pod_counts = [0; NUM_PODS];
+        // move directly from pod to pod
+        let from_pod_cost = self.pods.iter().enumerate().fold(0, |sum, (pod_idx, pod)| {
+            pod.iter()
+                .enumerate()
+                .filter(|(_, b)| **b != EMPTY)
+                .fold(sum, |sum, (pos, &b)| {
+                    let target_idx = target_pod_idx(b);
+                    if pod_idx == target_idx {
+                        sum
+                    } else {
+                        let dist_out_of_pod = pos + 1;
+                        move_into_pod_counts[target_idx] += 1;
+                        let dist = dist_out_of_pod + abs_diff(target_idx, pod_idx) * POD_GAP;
+                        sum + cost_per_step(b) * dist as u64
+                    }
+                })
+        });
+
+        // move from hall to pod
+        let from_hall_cost = self
+            .hallway
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| **b != EMPTY)
+            .fold(0, |sum, (pos, &b)| {
+                let target_idx = target_pod_idx(b);
+                move_into_pod_counts[target_idx] += 1;
+                sum + cost_per_step(b) * abs_diff(pos, hallway_pos_for_pod(target_idx)) as u64
+            });
+
+        let pod_fill_cost = move_into_pod_counts
+            .iter()
+            .enumerate()
+            .fold(0, |sum, (i, count)| {
+                sum + cost_per_step_by_idx(i) * count * (count + 1) / 2
+            });
+
+        from_pod_cost + from_hall_cost + pod_fill_cost
+    }
+
+    fn swap_pod_with_hallway(
+        &self,
+        pod_idx: usize,
+        pod_pos: usize,
+        hallway_pos: usize,
+    ) -> Graph<POD_LEN> {
+        let mut out = self.clone();
+        mem::swap(
+            &mut out.pods[pod_idx][pod_pos],
+            &mut out.hallway[hallway_pos],
+        );
+        let moved_val = match out.pods[pod_idx][pod_pos] {
+            EMPTY => out.hallway[hallway_pos],
+            b => b,
+        };
+        out.cost += cost_per_step(moved_val)
+            * (1 + pod_pos + abs_diff(hallway_pos, hallway_pos_for_pod(pod_idx))) as u64;
+        out
+    }
+
+    fn pod_target_pos(&self, pod_idx: usize) -> Option<usize> {
+        (0..POD_LEN)
+            .rev()
+            .find(|&pod_pos| self.pods[pod_idx][pod_pos] == EMPTY)
+    }
+
+    fn push_all_hallway_moves<I: Iterator<Item = usize>>(
+        &self,
+        range: I,
+        pod_idx: usize,
+        pod_pos: usize,
+        out: &mut Vec<Graph<POD_LEN>>,
+    ) {
+        out.extend(
+            range
+                .filter(|&h_pos| pos_not_in_front_of_pod(h_pos))
+                .take_while(|&h_pos| self.hallway[h_pos] == EMPTY)
+                .map(|h_pos| self.swap_pod_with_hallway(pod_idx, pod_pos, h_pos)),
+        );
+    }
+
+    /// The arrangements one move away, each with the energy of that move added on.
+    pub fn neighbours(&self) -> Vec<Graph<POD_LEN>> {
+        let mut out = vec![];
+        // moves from pods to hallway
+        for (pod_idx, pod) in self.pods.iter().enumerate() {
+            if let Some(pod_pos) = pod.iter().position(|b| *b != EMPTY) {
+                let h_pos = hallway_pos_for_pod(pod_idx);
+                self.push_all_hallway_moves(h_pos + 1..HALL_LEN, pod_idx, pod_pos, &mut out);
+                self.push_all_hallway_moves((0..h_pos).rev(), pod_idx, pod_pos, &mut out);
+            }
+        }
+
+        // moves from hallway to pods
+        for (pos, &val) in self
+            .hallway
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| **b != EMPTY)
+        {
+            let pod_idx = target_pod_idx(val);
+            // if the pod contains anything different, don't move there
+            if self.pods[pod_idx].iter().any(|&b| b != EMPTY && b != val) {
+                continue;
+            }
+            let target_pos = hallway_pos_for_pod(pod_idx);
+            let hall_range = if target_pos > pos {
+                pos + 1..target_pos + 1
+            } else {
+                target_pos..pos
+            };
+            if self.hallway[hall_range].iter().all(|b| *b == EMPTY) {
+                if let Some(pod_pos) = self.pod_target_pos(pod_idx) {
+                    out.push(self.swap_pod_with_hallway(pod_idx, pod_pos, pos));
+                }
+            }
+        }
+
+        out
+    }
+
+    /// The least energy it takes to organize the amphipods, or `None` if they get stuck.
+    pub fn min_cost(&self) -> Option<u64> {
+        self.astar(false).map(|(cost, _)| cost)
+    }
+
+    /// Every arrangement on the cheapest way to organize the amphipods, from this one to the
+    /// organized one, or `None` if they get stuck.
+    pub fn cheapest_path(&self) -> Option<Vec<Graph<POD_LEN>>> {
+        self.astar(true).map(|(_, path)| path)
+    }
+
+    /// Finds the cheapest way to organize the amphipods, along with every arrangement on the way
+    /// if `track` is set.
+    fn astar(&self, track: bool) -> Option<(u64, Vec<Graph<POD_LEN>>)> {
+        let mut min_costs = FxHashMap::default();
+        min_costs.insert((self.pods, self.hallway), 0);
+        let mut parents = FxHashMap::default();
+        let mut heap = BinaryHeap::new();
+        heap.push((Reverse(self.heuristic()), self.clone()));
+
+        loop {
+            cancel::check();
+            let (_, graph) = heap.pop()?;
+            if graph.is_solved() {
+                let mut path = vec![];
+                if track {
+                    // costs only fall going back through the parents, so this ends at the start
+                    let mut next = Some(graph.clone());
+                    while let Some(g) = next {
+                        next = parents.get(&(g.pods, g.hallway)).cloned();
+                        path.push(g);
+                    }
+                    path.reverse();
+                }
+                return Some((graph.cost, path));
+            }
+            let ns = graph.neighbours();
+            for n in ns {
+                let state = (n.pods, n.hallway);
+                if &n.cost < min_costs.get(&state).unwrap_or(&u64::MAX) {
+                    min_costs.insert(state, n.cost);
+                    if track {
+                        parents.insert(state, graph.clone());
+                    }
+                    heap.push((Reverse(n.cost + n.heuristic()), n));
+                }
+            }
+        }
+    }
+
+    fn snapshot(&self, step: usize) -> Snapshot {
+        Snapshot::new(step, self.to_string(), 6, |c| match c {
+            '#' => 1,
+            'A'..='D' => c as u8 - b'A' + 2,
+            _ => 0,
+        })
+    }
+}
+
+/// The starting positions of the amphipods, one row of side rooms at a time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Burrow {
+    rows: [[u8; NUM_PODS]; 2],
+}
+
+impl Burrow {
+    /// The burrow as the diagram shows it, for part 1.
+    pub fn folded(&self) -> Graph<2> {
+        Graph::new(self.rows)
+    }
+
+    /// The burrow for part 2, with the two folded-up rows of the diagram in the middle.
+    pub fn unfolded(&self) -> Graph<4> {
+        let [first, last] = self.rows;
+        Graph::new([first, *b"DCBA", *b"DBAC", last])
+    }
+}
+
+const DIAGRAM_TOP: [&str; 2] = ["#############", "#...........#"];
+
+pub fn parse(input: &str) -> Result<Burrow, ParseError> {
+    let mut lines = lines(DAY, input);
+    for expected in DIAGRAM_TOP {
+        let line = lines
+            .next()
+            .ok_or_else(|| end_of_input(DAY, input, format!("expected {:?}", expected)))?;
+        if line.text != expected {
+            return Err(line.error(line.text, format!("expected {:?}", expected)));
+        }
+    }
+
+    let mut rows = [[EMPTY; NUM_PODS]; 2];
+    let mut counts = [0; NUM_PODS];
+    for row in rows.iter_mut() {
+        let line = lines
+            .next()
+            .ok_or_else(|| end_of_input(DAY, input, "expected a row of side rooms"))?;
+        for (n, cell) in row.iter_mut().enumerate() {
+            let i = 1 + hallway_pos_for_pod(n);
+            match line.text.char_indices().nth(i) {
+                Some((_, c @ 'A'..='D')) => {
+                    *cell = c as u8;
+                    counts[target_pod_idx(c as u8)] += 1;
+                }
+                Some((j, c)) => {
+                    let bad = &line.text[j..j + c.len_utf8()];
+                    return Err(line.error(bad, "expected an amphipod 'A'-'D'"));
+                }
+                None => return Err(line.error_at_end("expected an amphipod 'A'-'D'")),
+            }
+        }
+    }
+    if counts.iter().any(|&count| count != 2) {
+        return Err(end_of_input(
+            DAY,
+            input,
+            "expected exactly two amphipods of each type",
+        ));
+    }
+
+    Ok(Burrow { rows })
+}
+
+impl<const POD_LEN: usize> Graph<POD_LEN> {
+    /// Creates an arrangement with an empty hallway, from the rows of side rooms from the top
+    /// down, where each amphipod is one of `b'A'..=b'D'`.
+    ///
+    /// Panics if any of the rows hold anything else.
+    pub fn new(rows: [[u8; NUM_PODS]; POD_LEN]) -> Graph<POD_LEN> {
+        assert!(
+            rows.iter().flatten().all(|b| (b'A'..=b'D').contains(b)),
+            "expected amphipods 'A'-'D'"
+        );
+        let mut pods = [[EMPTY; POD_LEN]; NUM_PODS];
+        for (i, row) in rows.iter().enumerate() {
+            for (pod, &b) in pods.iter_mut().zip(row) {
+                pod[i] = b;
+            }
+        }
+        Graph {
+            pods,
+            hallway: [EMPTY; HALL_LEN],
+            cost: 0,
+        }
+    }
+}
+
+impl<const POD_LEN: usize> Graph<POD_LEN> {
+    /// The energy spent reaching this arrangement.
+    pub fn cost(&self) -> u64 {
+        self.cost
+    }
+
+    /// What is in each space of the hallway, from left to right.
+    pub fn hallway(&self) -> impl Iterator<Item = Option<char>> + '_ {
+        self.hallway
+            .iter()
+            .map(|&b| (b != EMPTY).then_some(b as char))
+    }
+
+    /// What is in each space of a side room, numbered from 0 on the left, from the top down.
+    pub fn room(&self, index: usize) -> impl Iterator<Item = Option<char>> + '_ {
+        self.pods[index]
+            .iter()
+            .map(|&b| (b != EMPTY).then_some(b as char))
+    }
+}
+
+/// Draws the burrow like the puzzle does.
+#[allow(unstable_name_collisions)]
+impl<const POD_LEN: usize> Display for Graph<POD_LEN> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let byte_to_char = |b: u8| if b == EMPTY { '.' } else { b as char };
+        f.write_str("#############\n#").unwrap();
+        f.write_str(
+            &self
+                .hallway
+                .into_iter()
+                .map(byte_to_char)
+                .collect::<String>(),
+        )
+        .unwrap();
+        f.write_str("#\n").unwrap();
+
+        for i in 0..POD_LEN {
+            f.write_fmt(format_args!(
+                "###{}###\n",
+                self.pods
+                    .iter()
+                    .map(|p| byte_to_char(p[i]))
+                    .intersperse_with(|| '#')
+                    .collect::<String>(),
+            ))
+            .unwrap();
+        }
+        f.write_str("  #########  \n")
+    }
+}
+
+/// Whether both parts can be solved, since some arrangements leave the amphipods stuck.
+pub(crate) fn is_solvable(burrow: &Burrow) -> bool {
+    burrow.folded().min_cost().is_some() && burrow.unfolded().min_cost().is_some()
+}
+
+/// Organizes the amphipods, showing `observer` each move of the cheapest way to do it.
+fn organize<const POD_LEN: usize>(graph: Graph<POD_LEN>, observer: &mut dyn Observer) -> u64 {
+    let (cost, path) = graph.astar(observer.is_watching()).unwrap();
+    for (step, graph) in path.iter().enumerate() {
+        observer.observe(&graph.snapshot(step));
+    }
+    cost
+}
+
+pub fn part_1(burrow: &Burrow) -> u64 {
+    burrow.folded().min_cost().unwrap()
+}
+
+pub fn part_2(burrow: &Burrow) -> u64 {
+    burrow.unfolded().min_cost().unwrap()
+}
+
+/// The examples from the puzzle description, with the answers it gives for them.
+pub fn examples() -> Vec<Example> {
+    vec![(
+        "\
+#############
+#...........#
+###B#C#B#D###
+  #A#D#C#A#
+  #########
+",
+        Some(Answer::Int(12521)),
+        Some(Answer::Int(44169)),
+    )]
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = DAY;
+    type Input = Burrow;
+
+    fn parse(input: &str) -> Result<Burrow, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Burrow) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Burrow) -> Answer {
+        part_2(input).into()
+    }
+
+    fn trace(
+        input: &Burrow,
+        part: Part,
+        _params: &Params,
+        observer: &mut dyn Observer,
+    ) -> Option<Answer> {
+        Some(match part {
+            Part::One => organize(input.folded(), observer).into(),
+            Part::Two => organize(input.unfolded(), observer).into(),
+        })
+    }
+
+    fn examples() -> Vec<Example> {
+        examples()
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test() {
+        let input = parse(examples()[0].0).unwrap();
+        assert_eq!(12521, part_1(&input));
+        assert_eq!(44169, part_2(&input));
+        // the rooms are found by character, not by byte
+        for (row, column) in [("###é#C#B#D###", 4), ("##é#B#C#D###", 4)] {
+            let err = parse(&examples()[0].0.replace("###B#C#B#D###", row))
+                .err()
+                .unwrap();
+            assert_eq!((3, column), (err.line, err.column));
+        }
+
+        let mut snapshots = vec![];
+        assert_eq!(12521, organize(input.folded(), &mut snapshots));
+        assert!(snapshots[0].text.contains("###B#C#B#D###"));
+        assert!(snapshots
+            .last()
+            .unwrap()
+            .text
+            .contains("#...........#\n###A#B#C#D###"));
+        assert!(snapshots.iter().enumerate().all(|(i, s)| s.step == i));
+
+        let path = input.folded().cheapest_path().unwrap();
+        assert_eq!(snapshots.len(), path.len());
+        assert!(path.last().unwrap().is_solved());
+        assert_eq!(12521, path.last().unwrap().cost());
+        let start = &path[0];
+        assert!(start.hallway().all(|space| space.is_none()));
+        assert_eq!(
+            vec![Some('B'), Some('A')],
+            start.room(0).collect::<Vec<_>>()
+        );
+        assert!(start.neighbours().contains(&path[1]));
+    }
+}
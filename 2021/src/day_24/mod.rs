@@ -0,0 +1,209 @@
+use crate::{
+    parse::{end_of_input, lines},
+    Answer, Example, ParseError, Solution,
+};
+
+const DAY: u8 = 24;
+
+// as explained here https://www.reddit.com/r/adventofcode/comments/rnejv5/comment/hps5hgw/?utm_source=share&utm_medium=web2x&context=3
+
+/// The entire input is in this form repeated 14 times:
+///
+/// inp w
+/// mul x 0
+/// add x z
+/// mod x 26
+/// div z {a}
+/// add x {b}
+/// eql x w
+/// eql x 0
+/// mul y 0
+/// add y 25
+/// mul y x
+/// add y 1
+/// mul z y
+/// mul y 0
+/// add y w
+/// add y {c}
+/// mul y x
+/// add z y
+/// This in decompiled Python is
+///
+/// w = int(input())
+/// x = int((z % 26) + b != w)
+/// z //= a
+/// z *= 25*x+1
+/// z += (w+c)*x
+/// Another thing to note is that the a is 1 seven times and 26 the other seven times. In the block where a is 1, b is always between 10 and 16. It follows that z //= {a} line is no-op and (z % 26) + b != w is always true. So the decompiled code becomes:
+///
+/// w = int(input())
+/// z *= 26
+/// z += w+c
+/// So this block of code is "pushing" a digit of w+c in base 26. So to get 0 at the end, we have to "pop" these digits back out using z //= 26 and don't add any more back. Thus, in the lines with a=26, x = int((z % 26) + b != w) must be 0, which means the last pushed digit w_old+c must be equal to w_now-b.
+///
+/// For my particular input, it meant that
+///
+/// I[2]+ 6-14 == I[3]
+/// I[4]+ 9- 7 == I[5]
+/// I[8]+ 1- 7 == I[9]
+/// I[7]+ 3- 8 == I[10]
+/// I[6]+14- 7 == I[11]
+/// I[1]+ 5- 5 == I[12]
+/// I[0]+15-10 == I[13]
+/// where I is the array of input.
+const NUM_DIGITS: usize = 14;
+
+// each block's instructions, where a trailing space marks an operand that varies between blocks
+const BLOCK: [&str; 18] = [
+    "inp w", "mul x 0", "add x z", "mod x 26", "div z ", "add x ", "eql x w", "eql x 0", "mul y 0",
+    "add y 25", "mul y x", "add y 1", "mul z y", "mul y 0", "add y w", "add y ", "mul y x",
+    "add z y",
+];
+
+/// The `{b}` and `{c}` operands of one block, as described above.
+#[derive(Debug, Clone, Copy)]
+pub struct Block {
+    b: i64,
+    c: i64,
+}
+
+pub fn parse(input: &str) -> Result<Vec<Block>, ParseError> {
+    let mut lines = lines(DAY, input).filter(|line| !line.text.trim().is_empty());
+    let mut blocks = Vec::with_capacity(NUM_DIGITS);
+    // the {c} operands of the blocks pushed so far
+    let mut pushed = vec![];
+    for _ in 0..NUM_DIGITS {
+        let mut operands = vec![];
+        for expected in BLOCK {
+            let line = lines
+                .next()
+                .ok_or_else(|| end_of_input(DAY, input, format!("expected {:?}", expected)))?;
+            if expected.ends_with(' ') {
+                let operand = line.strip_prefix(line.text, expected)?;
+                operands.push((line, operand, line.parse(operand)?));
+            } else if line.text != expected {
+                return Err(line.error(line.text, format!("expected {:?}", expected)));
+            }
+        }
+        let [(a_line, a_text, a), (b_line, b_text, b), (c_line, c_text, c)] = operands[..] else {
+            unreachable!("every block has three operands");
+        };
+
+        // the solver relies on every block either pushing or popping a base 26 digit
+        match a {
+            // a {b} of 10 or more never matches a digit, so these always push
+            1 if b < 10 => {
+                return Err(b_line.error(b_text, "expected a {b} of at least 10 in a push"))
+            }
+            // and w+{c} must fit in a base 26 digit
+            1 if !(0..=16).contains(&c) => {
+                return Err(c_line.error(c_text, "expected a digit offset from 0 to 16"))
+            }
+            1 => pushed.push(c),
+            26 => {
+                let pushed = pushed
+                    .pop()
+                    .ok_or_else(|| a_line.error(a_text, "expected a push before every pop"))?;
+                // otherwise no pair of digits makes the pop match the push, and a positive {b}
+                // would look like a push to the solver
+                if !(-8 - pushed..=(8 - pushed).min(0)).contains(&b) {
+                    return Err(b_line.error(b_text, "expected a pop that can match its push"));
+                }
+            }
+            _ => return Err(a_line.error(a_text, "expected a block that pushes or pops a digit")),
+        }
+        blocks.push(Block { b, c });
+    }
+    if let Some(line) = lines.next() {
+        return Err(line.error(line.text, "expected only 14 blocks"));
+    }
+    if !pushed.is_empty() {
+        return Err(end_of_input(DAY, input, "expected as many pops as pushes"));
+    }
+
+    Ok(blocks)
+}
+
+fn solve(blocks: &[Block]) -> (i64, i64) {
+    let mut stack = vec![];
+    let (mut max, mut min) = (99999999999999, 11111111111111);
+
+    for (i, block) in blocks.iter().enumerate() {
+        let (a, b) = (block.b, block.c);
+
+        if a > 0 {
+            stack.push((i, b));
+            continue;
+        }
+        let (j, b) = stack.pop().unwrap();
+
+        max -= ((a + b) * 10_i64.pow((13 - if a > -b { j } else { i }) as u32)).abs();
+        min += ((a + b) * 10_i64.pow((13 - if a < -b { j } else { i }) as u32)).abs();
+    }
+
+    (max, min)
+}
+
+pub fn part_1(blocks: &[Block]) -> i64 {
+    solve(blocks).0
+}
+
+pub fn part_2(blocks: &[Block]) -> i64 {
+    solve(blocks).1
+}
+
+/// The examples from the puzzle description, of which there are none: the one it shows isn't a
+/// valid MONAD program and has no answers.
+pub fn examples() -> Vec<Example> {
+    vec![]
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = DAY;
+    type Input = Vec<Block>;
+
+    fn parse(input: &str) -> Result<Vec<Block>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Vec<Block>) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Vec<Block>) -> Answer {
+        part_2(input).into()
+    }
+
+    fn examples() -> Vec<Example> {
+        examples()
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test() {
+        // the first block pushes w+40, which no pop can match
+        let input = crate::generate::generate(DAY, 0, 1).unwrap();
+        let mut lines: Vec<_> = input.lines().collect();
+        assert_eq!("add y 15", lines[15]);
+        lines[15] = "add y 40";
+        let err = parse(&lines.join("\n")).unwrap_err();
+        assert_eq!((16, 7), (err.line, err.column));
+    }
+
+    // the example input for this puzzle isn't a valid program, so this relies on the real input
+    #[test]
+    #[ignore = "needs the real input, run with --ignored once it's checked out"]
+    pub fn test_input() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/../input/2021/day24.txt");
+        let input = std::fs::read_to_string(path).unwrap_or_else(|e| panic!("{}: {}", path, e));
+        let input = parse(&input).unwrap();
+        assert_eq!(91297395919993, part_1(&input));
+        assert_eq!(71131151917891, part_2(&input));
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{
+    cancel,
+    grid::Grid,
+    trace::{self, Observer, Snapshot},
+    Answer, Example, Params, ParseError, Part, Solution,
+};
+
+const DAY: u8 = 25;
+
+pub fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse_chars(
+        DAY,
+        input,
+        |c| matches!(c, '>' | 'v' | '.').then_some(c as u8),
+        "'>', 'v' or '.'",
+    )
+}
+
+pub(crate) fn step(grid: &mut Grid<u8>) -> bool {
+    let mut changed = false;
+    for (herd, direction) in [(b'>', (1, 0)), (b'v', (0, 1))] {
+        let mut new_grid = grid.clone();
+        for p in grid.points() {
+            let next = grid.wrapping_offset(p, direction);
+            if grid[p] == herd && grid[next] == b'.' {
+                new_grid[p] = b'.';
+                new_grid[next] = herd;
+                changed = true;
+            }
+        }
+        *grid = new_grid;
+    }
+    changed
+}
+
+fn snapshot(step: usize, grid: &Grid<u8>) -> Snapshot {
+    let text = grid.map(|&b| b as char).to_string();
+    Snapshot::new(step, text, 3, |c| match c {
+        '>' => 1,
+        'v' => 2,
+        _ => 0,
+    })
+}
+
+fn solve(grid: &mut Grid<u8>, observer: &mut dyn Observer) -> usize {
+    trace::show(observer, || snapshot(0, grid));
+    (1..)
+        .find(|&n| {
+            // some herds never settle, and then only a timeout stops this
+            cancel::check();
+            let moved = step(grid);
+            trace::show(observer, || snapshot(n, grid));
+            !moved
+        })
+        .unwrap()
+}
+
+pub fn part_1(grid: &Grid<u8>) -> usize {
+    solve(&mut grid.clone(), &mut ())
+}
+
+/// The examples from the puzzle description, with the answers it gives for them.
+pub fn examples() -> Vec<Example> {
+    vec![(
+        "\
+v...>>.vv>
+.vv>>.vv..
+>>.>v>...v
+>>v>>.>.v.
+v>v.vv.v..
+>.>>..v...
+.vv..>.>v.
+v.v..>>v.v
+....v..v.>",
+        Some(Answer::Int(58)),
+        Some(Answer::None),
+    )]
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = DAY;
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Grid<u8>) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(_: &Grid<u8>) -> Answer {
+        Answer::None
+    }
+
+    fn trace(
+        input: &Grid<u8>,
+        part: Part,
+        _params: &Params,
+        observer: &mut dyn Observer,
+    ) -> Option<Answer> {
+        match part {
+            Part::One => Some(solve(&mut input.clone(), observer).into()),
+            Part::Two => None,
+        }
+    }
+
+    fn examples() -> Vec<Example> {
+        examples()
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test() {
+        let input = parse(examples()[0].0).unwrap();
+        assert_eq!(58, part_1(&input));
+
+        let mut snapshots = vec![];
+        solve(&mut input.clone(), &mut snapshots);
+        assert_eq!(59, snapshots.len());
+        assert_eq!(snapshots[57].text, snapshots[58].text);
+        assert!(snapshots[58].text.starts_with("..>>v>vv..\n"));
+    }
+}
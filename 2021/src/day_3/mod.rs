@@ -0,0 +1,140 @@
+use crate::{
+    parse::{end_of_input, lines},
+    Answer, Example, ParseError, Solution,
+};
+
+const DAY: u8 = 3;
+
+fn get_most_common_bit_string(report: &[String]) -> String {
+    report
+        .iter()
+        .map(|s| {
+            s.chars()
+                .map(|c| if c == '1' { 1 } else { -1 })
+                .collect::<Vec<_>>()
+        })
+        .reduce(|v1, v2| v1.iter().zip(v2.iter()).map(|(a1, a2)| a1 + a2).collect())
+        .unwrap_or_default()
+        .iter()
+        .map(|count| if *count >= 0 { '1' } else { '0' })
+        .collect()
+}
+
+fn invert_bit_string(bits: &str) -> String {
+    bits.chars()
+        .map(|c| if c == '0' { '1' } else { '0' })
+        .collect()
+}
+
+pub fn part_1(report: &[String]) -> u64 {
+    let most_common_bits = get_most_common_bit_string(report);
+    let gamma = u64::from_str_radix(&most_common_bits, 2).unwrap();
+    let epsilon = u64::from_str_radix(&invert_bit_string(&most_common_bits), 2).unwrap();
+
+    gamma * epsilon
+}
+
+fn reading<T>(lines: &[&str], compare: T) -> u64
+where
+    T: Fn(usize, usize) -> bool,
+{
+    let mut remaining = lines.to_vec();
+    let mut i = 0;
+    while remaining.len() > 1 && i < remaining[0].len() {
+        let num_matches = remaining
+            .iter()
+            .filter(|s| s.chars().nth(i) == Some('1'))
+            .count();
+
+        // if every number has the same bit here then there's nothing to choose between
+        let selected = if num_matches == remaining.len()
+            || (num_matches != 0 && compare(num_matches, remaining.len()))
+        {
+            '1'
+        } else {
+            '0'
+        };
+
+        remaining.retain(|s| s.chars().nth(i) == Some(selected));
+
+        i += 1;
+    }
+
+    u64::from_str_radix(remaining[0], 2).unwrap()
+}
+
+pub fn part_2(report: &[String]) -> u64 {
+    let lines = report.iter().map(String::as_str).collect::<Vec<_>>();
+    let oxy = reading(&lines, |num_ones, len| 2 * num_ones >= len);
+    let co2 = reading(&lines, |num_ones, len| 2 * num_ones < len);
+
+    oxy * co2
+}
+
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    let mut width = None;
+    let report = lines(DAY, input)
+        .map(|line| {
+            if let Some(bad) = line.text.find(|c| c != '0' && c != '1') {
+                return Err(line.error(&line.text[bad..], "expected only '0' and '1'"));
+            }
+            let expected = *width.get_or_insert(line.text.len());
+            if !(1..=32).contains(&line.text.len()) {
+                Err(line.error(line.text, "expected between 1 and 32 bits"))
+            } else if line.text.len() != expected {
+                Err(line.error(line.text, format!("expected {} bits", expected)))
+            } else {
+                Ok(line.text.to_owned())
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if report.is_empty() {
+        return Err(end_of_input(DAY, input, "expected a binary number"));
+    }
+    Ok(report)
+}
+
+/// The examples from the puzzle description, with the answers it gives for them.
+pub fn examples() -> Vec<Example> {
+    vec![(
+        "00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010",
+        Some(Answer::Int(198)),
+        Some(Answer::Int(230)),
+    )]
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = DAY;
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Vec<String>) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Vec<String>) -> Answer {
+        part_2(input).into()
+    }
+
+    fn examples() -> Vec<Example> {
+        examples()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = parse(examples()[0].0).unwrap();
+        assert_eq!(part_1(&input), 198);
+        assert_eq!(part_2(&input), 230);
+    }
+}
@@ -0,0 +1,261 @@
+use std::fmt::Display;
+
+use arrayvec::ArrayVec;
+
+use crate::{
+    parse::{first_line, lines, sections, Line},
+    Answer, Example, ParseError, Solution,
+};
+
+const DAY: u8 = 4;
+
+pub type BoardNum = i64;
+
+/// A bingo board, which keeps track of the numbers marked on it so far.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Board<const WIDTH: usize, const HEIGHT: usize> {
+    rows: ArrayVec<ArrayVec<(BoardNum, bool), WIDTH>, HEIGHT>,
+    last_num: Option<BoardNum>,
+    row_tally: [usize; HEIGHT],
+    col_tally: [usize; WIDTH],
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> Board<WIDTH, HEIGHT> {
+    /// Creates a board with nothing marked.
+    pub fn new(rows: [[BoardNum; WIDTH]; HEIGHT]) -> Board<WIDTH, HEIGHT> {
+        Board {
+            rows: rows
+                .iter()
+                .map(|row| row.iter().map(|&n| (n, false)).collect())
+                .collect(),
+            last_num: None,
+            row_tally: [0; HEIGHT],
+            col_tally: [0; WIDTH],
+        }
+    }
+
+    fn parse(lines: &[Line]) -> Result<Board<WIDTH, HEIGHT>, ParseError> {
+        let mut rows = ArrayVec::new();
+        for line in lines {
+            if rows.is_full() {
+                return Err(line.error(line.text, "expected a blank line between boards"));
+            }
+            let mut row = ArrayVec::new();
+            for num in line.text.split_whitespace() {
+                if row.is_full() {
+                    return Err(line.error(num, format!("expected {} numbers", WIDTH)));
+                }
+                row.push((line.parse(num)?, false));
+            }
+            if !row.is_full() {
+                return Err(line.error_at_end(format!("expected {} numbers", WIDTH)));
+            }
+            rows.push(row);
+        }
+        if let (false, Some(last)) = (rows.is_full(), lines.last()) {
+            return Err(last.error_at_end(format!("expected {} rows", HEIGHT)));
+        }
+
+        Ok(Board {
+            rows,
+            last_num: None,
+            row_tally: [0; HEIGHT],
+            col_tally: [0; WIDTH],
+        })
+    }
+
+    /// The number at a row and column, and whether it has been marked.
+    pub fn get(&self, row: usize, column: usize) -> Option<(BoardNum, bool)> {
+        self.rows.get(row)?.get(column).copied()
+    }
+
+    /// The sum of the unmarked numbers times the number that won, once the board has won.
+    pub fn score(&self) -> Option<BoardNum> {
+        let unmarked = self
+            .rows
+            .iter()
+            .flat_map(|row| row.iter().filter(|x| !x.1).map(|x| x.0))
+            .sum::<BoardNum>();
+        Some(unmarked * self.last_num?)
+    }
+
+    /// Marks a drawn number, returning whether the board has won.
+    ///
+    /// Once a board has won, nothing more is marked on it.
+    pub fn mark(&mut self, num: BoardNum) -> bool {
+        if self.has_won() {
+            return true;
+        }
+
+        for (i, row) in self.rows.iter_mut().enumerate() {
+            for (j, col) in row.iter_mut().enumerate() {
+                if !col.1 && col.0 == num {
+                    col.1 = true;
+                    self.row_tally[i] += 1;
+                    self.col_tally[j] += 1;
+                    if self.row_tally[i] == HEIGHT || self.col_tally[j] == WIDTH {
+                        self.last_num = Some(num);
+                        return true;
+                    }
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Whether a whole row or column has been marked.
+    pub fn has_won(&self) -> bool {
+        self.last_num.is_some()
+    }
+}
+
+/// Shows the board like the puzzle does, with a `*` after each marked number.
+impl<const WIDTH: usize, const HEIGHT: usize> Display for Board<WIDTH, HEIGHT> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.rows.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            let cells: Vec<_> = row
+                .iter()
+                .map(|&(n, marked)| format!("{:>2}{}", n, if marked { "*" } else { " " }))
+                .collect();
+            write!(f, "{}", cells.join(" ").trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+/// The numbers to be drawn and the boards playing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bingo {
+    nums: Vec<BoardNum>,
+    boards: Vec<Board<5, 5>>,
+}
+
+impl Bingo {
+    pub fn new(nums: Vec<BoardNum>, boards: Vec<Board<5, 5>>) -> Bingo {
+        Bingo { nums, boards }
+    }
+
+    pub fn numbers(&self) -> &[BoardNum] {
+        &self.nums
+    }
+
+    pub fn boards(&self) -> &[Board<5, 5>] {
+        &self.boards
+    }
+
+    /// Draws every number, returning the score of each board in the order they win.
+    ///
+    /// Boards that win on the same number are in the order they were given, and boards that
+    /// never win are left out.
+    pub fn winning_scores(&self) -> Vec<BoardNum> {
+        let mut boards = self.boards.clone();
+        let mut scores = vec![];
+        for &n in &self.nums {
+            for board in boards.iter_mut().filter(|board| !board.has_won()) {
+                if board.mark(n) {
+                    scores.extend(board.score());
+                }
+            }
+        }
+        scores
+    }
+}
+
+pub fn parse(input: &str) -> Result<Bingo, ParseError> {
+    let first = first_line(DAY, input, "expected the drawn numbers")?;
+    let nums = first.list(first.text, ",")?;
+
+    let rest: Vec<_> = lines(DAY, input).skip(1).collect();
+    let boards = sections(&rest)
+        .map(Board::parse)
+        .collect::<Result<_, _>>()?;
+
+    Ok(Bingo { nums, boards })
+}
+
+pub fn part_1(bingo: &Bingo) -> BoardNum {
+    *bingo.winning_scores().first().expect("No winner found!")
+}
+
+pub fn part_2(bingo: &Bingo) -> BoardNum {
+    *bingo
+        .winning_scores()
+        .last()
+        .expect("No 'last' winner found!")
+}
+
+/// The examples from the puzzle description, with the answers it gives for them.
+pub fn examples() -> Vec<Example> {
+    vec![(
+        "\
+7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7",
+        Some(Answer::Int(4512)),
+        Some(Answer::Int(1924)),
+    )]
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = DAY;
+    type Input = Bingo;
+
+    fn parse(input: &str) -> Result<Bingo, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Bingo) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Bingo) -> Answer {
+        part_2(input).into()
+    }
+
+    fn examples() -> Vec<Example> {
+        examples()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = parse(examples()[0].0).unwrap();
+
+        assert_eq!(part_1(&input), 4512);
+        assert_eq!(part_2(&input), 1924);
+
+        let mut board = Board::new([[1, 2], [3, 4]]);
+        assert!(!board.mark(2));
+        assert_eq!(None, board.score());
+        assert!(board.mark(4));
+        assert_eq!(Some(4 * 4), board.score());
+        assert_eq!(Some((3, false)), board.get(1, 0));
+        assert_eq!(" 1   2*\n 3   4*", board.to_string());
+    }
+}
@@ -1,32 +1,34 @@
 use std::cmp::{max, min};
 
+use crate::{parse::lines, Answer, Example, ParseError, Solution};
+
+const DAY: u8 = 5;
+
 type Grid = Vec<Vec<u64>>;
 type Pair = (usize, usize);
 
-#[derive(Copy, Clone)]
-struct Line {
+#[derive(Debug, Copy, Clone)]
+pub struct Line {
     start: Pair,
     end: Pair,
 }
 
-fn parse_coords(coords: &str) -> Pair {
-    let (start, end) = coords.split_once(",").unwrap();
-    (
-        start.parse::<usize>().unwrap(),
-        end.parse::<usize>().unwrap(),
-    )
-}
-
-fn parse(contents: &str) -> Vec<Line> {
-    contents
-        .lines()
+pub fn parse(input: &str) -> Result<Vec<Line>, ParseError> {
+    lines(DAY, input)
         .map(|line| {
-            line.split_once(" -> ")
-                .map(|pairs| Line {
-                    start: parse_coords(pairs.0),
-                    end: parse_coords(pairs.1),
-                })
-                .unwrap()
+            let (start, end) = line.split_once(line.text, " -> ")?;
+            let (x1, y1): (usize, usize) = line.pair(start, ",")?;
+            let (x2, y2): (usize, usize) = line.pair(end, ",")?;
+            if x1 != x2 && y1 != y2 && x1.abs_diff(x2) != y1.abs_diff(y2) {
+                return Err(line.error(
+                    line.text,
+                    "expected a horizontal, vertical or diagonal line",
+                ));
+            }
+            Ok(Line {
+                start: (x1, y1),
+                end: (x2, y2),
+            })
         })
         .collect()
 }
@@ -97,23 +99,18 @@ fn solve(lines: &[Line], handle_diag_line: impl Fn(&mut Grid, Line)) -> usize {
     grid_count(grid, 2)
 }
 
-pub fn part_1(input: &str) -> usize {
-    let lines = parse(input);
-    solve(&lines, |_, _| {})
+pub fn part_1(lines: &[Line]) -> usize {
+    solve(lines, |_, _| {})
 }
 
-pub fn part_2(input: &str) -> usize {
-    let lines = parse(input);
-    solve(&lines, add_diag_line)
+pub fn part_2(lines: &[Line]) -> usize {
+    solve(lines, add_diag_line)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test() {
-        let input = "\
+/// The examples from the puzzle description, with the answers it gives for them.
+pub fn examples() -> Vec<Example> {
+    vec![(
+        "\
 0,9 -> 5,9
 8,0 -> 0,8
 9,4 -> 3,4
@@ -124,11 +121,48 @@ mod tests {
 3,4 -> 1,4
 0,0 -> 8,8
 5,5 -> 8,2
-";
-        assert_eq!(5, part_1(input));
-        assert_eq!(12, part_2(input));
+",
+        Some(Answer::Int(5)),
+        Some(Answer::Int(12)),
+    )]
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = DAY;
+    type Input = Vec<Line>;
+
+    fn parse(input: &str) -> Result<Vec<Line>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Vec<Line>) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Vec<Line>) -> Answer {
+        part_2(input).into()
+    }
+
+    fn examples() -> Vec<Example> {
+        examples()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = parse(examples()[0].0).unwrap();
+        assert_eq!(5, part_1(&input));
+        assert_eq!(12, part_2(&input));
+
+        let input = parse("1,1 -> 3,3\n3,3 -> 1,1").unwrap();
+        assert_eq!(part_2(&input), 3);
 
-        let input = "1,1 -> 3,3\n3,3 -> 1,1";
-        assert_eq!(part_2(input), 3);
+        assert_eq!(2, parse("1,1 -> 3,3\n0,0 -> 1,3").unwrap_err().line);
     }
 }
@@ -0,0 +1,166 @@
+use crate::{
+    num::{BigUint, Unsigned},
+    parse::first_line,
+    Answer, Example, Param, Params, ParseError, Part, Solution,
+};
+
+const DAY: u8 = 6;
+const MAX_AGE: usize = 8;
+const REBIRTH_AGE: usize = 6;
+type Fishies = [u64; MAX_AGE + 1];
+
+/// Moves the fish on by up to `generations`, stopping before a generation whose count would
+/// overflow `N`, and returns how many generations went by.
+fn advance<N: Unsigned>(fishies: &mut [N; MAX_AGE + 1], generations: i64) -> i64 {
+    for done in 0..generations {
+        // the fish at the end of their cycle restart it, alongside the ones a day younger
+        let Some(restarted) = fishies[REBIRTH_AGE + 1].try_add(&fishies[0]) else {
+            return done;
+        };
+        fishies.rotate_left(1);
+        fishies[REBIRTH_AGE] = restarted;
+    }
+    generations
+}
+
+fn total<N: Unsigned>(fishies: &[N; MAX_AGE + 1]) -> Option<N> {
+    fishies
+        .iter()
+        .try_fold(N::from_u64(0), |total, count| total.try_add(count))
+}
+
+/// Counts the fish in `N`, or returns `None` if there are too many for it.
+pub fn population_after_generations<N: Unsigned>(fishies: &Fishies, generations: i64) -> Option<N> {
+    let mut fishies = fishies.map(N::from_u64);
+    if advance(&mut fishies, generations) < generations {
+        return None;
+    }
+    total(&fishies)
+}
+
+pub fn parse(input: &str) -> Result<Fishies, ParseError> {
+    let line = first_line(DAY, input, "expected the ages of the fish")?;
+    let ages = line.list_with(line.text.trim(), ",", |n| match line.parse(n)? {
+        age @ 0..=MAX_AGE => Ok(age),
+        _ => Err(line.error(n, format!("expected an age up to {}", MAX_AGE))),
+    })?;
+    let mut counts = [0; MAX_AGE + 1];
+    for age in ages {
+        counts[age] += 1;
+    }
+    Ok(counts)
+}
+
+// the population grows about tenfold every 26 days, and the last day allowed, with a count of
+// nearly 4000 digits, still takes well under a second
+const PART_1_DAYS: Param = Param {
+    name: "part1_days",
+    help: "how many days part 1 simulates",
+    default: 80,
+    range: 0..=100_000,
+};
+const PART_2_DAYS: Param = Param {
+    name: "part2_days",
+    help: "how many days part 2 simulates",
+    default: 256,
+    range: 0..=100_000,
+};
+
+pub fn part_1(fishies: &Fishies) -> u64 {
+    population_after_generations(fishies, PART_1_DAYS.default).unwrap()
+}
+
+pub fn part_2(fishies: &Fishies) -> u64 {
+    population_after_generations(fishies, PART_2_DAYS.default).unwrap()
+}
+
+/// The examples from the puzzle description, with the answers it gives for them.
+pub fn examples() -> Vec<Example> {
+    vec![(
+        "3,4,3,1,2",
+        Some(Answer::Int(5934)),
+        Some(Answer::Int(26984457539)),
+    )]
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = DAY;
+    type Input = Fishies;
+    const PARAMS: &'static [Param] = &[PART_1_DAYS, PART_2_DAYS];
+
+    fn parse(input: &str) -> Result<Fishies, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Fishies) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Fishies) -> Answer {
+        part_2(input).into()
+    }
+
+    fn solve_with(input: &Fishies, part: Part, params: &Params) -> Answer {
+        let days = match part {
+            Part::One => params.get(&PART_1_DAYS),
+            Part::Two => params.get(&PART_2_DAYS),
+        };
+        // only count in the wider types once the narrower ones overflow, since they're slower,
+        // carrying on from where the narrower ones stopped
+        let mut narrow = *input;
+        let mut done = advance(&mut narrow, days);
+        if let (true, Some(total)) = (done == days, total(&narrow)) {
+            return total.into();
+        }
+        let mut wide = narrow.map(u128::from);
+        done += advance(&mut wide, days - done);
+        if let (true, Some(total)) = (done == days, total(&wide)) {
+            return total.into();
+        }
+        let mut big = wide.map(BigUint::from);
+        advance(&mut big, days - done);
+        total(&big).unwrap().into()
+    }
+
+    fn examples() -> Vec<Example> {
+        examples()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = parse(examples()[0].0).unwrap();
+        assert_eq!(5934, part_1(&input));
+        assert_eq!(26984457539, part_2(&input));
+
+        let mut params = Params::default();
+        params.set(Day6::PARAMS, "part1_days=18").unwrap();
+        assert_eq!(
+            Answer::Int(26),
+            Day6::solve_with(&input, Part::One, &params)
+        );
+        assert_eq!(None, population_after_generations::<u64>(&input, 500));
+        let wide = population_after_generations::<u128>(&input, 500).unwrap();
+        let big = population_after_generations::<BigUint>(&input, 500).unwrap();
+        assert_eq!(wide.to_string(), big.to_string());
+        params.set(Day6::PARAMS, "part1_days=500").unwrap();
+        assert_eq!(
+            Answer::from(wide),
+            Day6::solve_with(&input, Part::One, &params)
+        );
+        params.set(Day6::PARAMS, "part1_days=2000").unwrap();
+        let Answer::Big(n) = Day6::solve_with(&input, Part::One, &params) else {
+            panic!("expected an answer too big for an i128")
+        };
+        assert_eq!(
+            "25930134485516959540654250898620295097277946359266027842882316971109265815041",
+            n.to_string()
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{parse::first_line, Answer, Example, ParseError, Solution};
+
+const DAY: u8 = 7;
+
+type Position = i64;
+
+pub fn parse(input: &str) -> Result<Vec<Position>, ParseError> {
+    let line = first_line(DAY, input, "expected the positions of the crabs")?;
+    line.list(line.text.trim(), ",")
+}
+
+fn abs_diff<T: std::ops::Sub<Output = T> + Ord>(x: T, y: T) -> T {
+    if x < y {
+        y - x
+    } else {
+        x - y
+    }
+}
+
+fn triangle_diff(x: Position, y: Position) -> Position {
+    let diff = abs_diff(x, y);
+    diff * (diff + 1) / 2
+}
+
+fn take_diff_sum<F>(vals: &[Position], take: usize, target: Position, map: F) -> Position
+where
+    F: Fn(Position, Position) -> Position,
+{
+    vals.iter().take(take).map(|n| map(*n, target)).sum()
+}
+
+fn solve<F>(crabs: &[Position], diff_cost: F) -> i64
+where
+    F: Fn(Position, Position) -> Position + Copy,
+{
+    let mut best_pos = crabs[0];
+    let mut best_sum = 0;
+    for i in 0..crabs.len() {
+        best_sum += diff_cost(crabs[i], best_pos);
+        let diff = (crabs[i] - best_pos).signum();
+        let mut pos = best_pos;
+        loop {
+            pos += diff;
+            let sum = take_diff_sum(crabs, i + 1, pos, diff_cost);
+            if sum < best_sum {
+                best_pos = pos;
+                best_sum = sum;
+            } else {
+                break;
+            }
+        }
+    }
+
+    best_sum
+}
+
+pub fn part_1(crabs: &[Position]) -> i64 {
+    solve(crabs, abs_diff)
+}
+
+pub fn part_2(crabs: &[Position]) -> i64 {
+    solve(crabs, triangle_diff)
+}
+
+/// The examples from the puzzle description, with the answers it gives for them.
+pub fn examples() -> Vec<Example> {
+    vec![(
+        "16,1,2,0,4,2,7,1,2,14",
+        Some(Answer::Int(37)),
+        Some(Answer::Int(168)),
+    )]
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = DAY;
+    type Input = Vec<Position>;
+
+    fn parse(input: &str) -> Result<Vec<Position>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Vec<Position>) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Vec<Position>) -> Answer {
+        part_2(input).into()
+    }
+
+    fn examples() -> Vec<Example> {
+        examples()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = parse(examples()[0].0).unwrap();
+        assert_eq!(37, part_1(&input));
+        assert_eq!(168, part_2(&input));
+    }
+}
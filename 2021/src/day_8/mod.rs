@@ -0,0 +1,192 @@
+use itertools::Itertools;
+
+use crate::{
+    parse::{lines, Line},
+    Answer, Example, ParseError, Solution,
+};
+
+const DAY: u8 = 8;
+const NUM_SIGNALS: usize = 10;
+const NUM_OUTPUTS: usize = 4;
+
+pub fn part_1(patterns: &[Pattern]) -> usize {
+    patterns
+        .iter()
+        .flat_map(|p| p.outputs.iter().map(Vec::len))
+        .filter(|&len| len != 5 && len != 6)
+        .count()
+}
+
+type Word = Vec<u8>;
+#[derive(Debug)]
+pub struct Pattern {
+    signals: Vec<Word>,
+    outputs: Vec<Word>,
+    value: u32,
+}
+
+fn parse_words(line: &Line, words: &str, count: usize) -> Result<Vec<Word>, ParseError> {
+    let words = words
+        .split_whitespace()
+        .map(|word| {
+            let bytes: Word = word.bytes().collect();
+            if !bytes.iter().all(|b| (b'a'..=b'g').contains(b)) || !bytes.iter().all_unique() {
+                return Err(line.error(word, "expected distinct segments from 'a' to 'g'"));
+            }
+            Ok(bytes)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if words.len() != count {
+        return Err(line.error(line.text, format!("expected {} patterns", count)));
+    }
+    Ok(words)
+}
+
+pub fn parse(input: &str) -> Result<Vec<Pattern>, ParseError> {
+    lines(DAY, input)
+        .map(|line| {
+            let (signals, outputs) = line.split_once(line.text, " | ")?;
+            let mut pattern = Pattern {
+                signals: parse_words(&line, signals, NUM_SIGNALS)?,
+                outputs: parse_words(&line, outputs, NUM_OUTPUTS)?,
+                value: 0,
+            };
+            pattern.value = decode(&pattern)
+                .ok_or_else(|| line.error(signals, "expected the patterns of all ten digits"))?;
+            Ok(pattern)
+        })
+        .collect()
+}
+
+fn vec_eq<T: Eq>(a: &[T], b: &[T]) -> bool {
+    a.len() == b.len() && a.iter().zip(b.iter()).all(|(x, y)| *x == *y)
+}
+
+fn decode(pattern: &Pattern) -> Option<u32> {
+    let mut freqs = [0; 7];
+    let mut lengths_found_in = [[false; 7]; 7];
+    for signal in pattern.signals.iter() {
+        for c in signal.iter().map(|c| c - b'a') {
+            freqs[c as usize] += 1;
+            lengths_found_in[c as usize][signal.len() - 1] = true;
+        }
+    }
+
+    let lengths_counts = lengths_found_in.map(|counts| counts.iter().filter(|b| **b).count());
+
+    // these properties just happen to be enough to uniquely identify the segments
+    let mut map = [b'0'; 7];
+    for i in 0..freqs.len() {
+        let old_digit = b'a' + i as u8;
+        match (freqs[i], lengths_counts[i]) {
+            (8, 4) => map[0] = old_digit,
+            (6, _) => map[1] = old_digit,
+            (8, 6) => map[2] = old_digit,
+            (7, 4) => map[3] = old_digit,
+            (4, _) => map[4] = old_digit,
+            (9, _) => map[5] = old_digit,
+            (7, 3) => map[6] = old_digit,
+            _ => return None,
+        }
+    }
+
+    let to_word =
+        |indexes: &[usize]| -> Word { indexes.iter().map(|&n| map[n]).sorted().collect() };
+
+    let mapping: [Word; 10] = [
+        to_word(&[0, 1, 2, 4, 5, 6]),
+        to_word(&[2, 5]),
+        to_word(&[0, 2, 3, 4, 6]),
+        to_word(&[0, 2, 3, 5, 6]),
+        to_word(&[1, 2, 3, 5]),
+        to_word(&[0, 1, 3, 5, 6]),
+        to_word(&[0, 1, 3, 4, 5, 6]),
+        to_word(&[0, 2, 5]),
+        to_word(&[0, 1, 2, 3, 4, 5, 6]),
+        to_word(&[0, 1, 2, 3, 5, 6]),
+    ];
+
+    // we need to sort the patterns so they can be compared directly with the sorted bytes above
+    let digit = |pattern: &Word| {
+        let sorted: Word = pattern.iter().copied().sorted().collect();
+        mapping.iter().position(|num| vec_eq(num, &sorted))
+    };
+
+    // the segment properties can match by coincidence, so check the wiring shows every digit
+    let shown: Option<Vec<_>> = pattern.signals.iter().map(digit).collect();
+    if !shown?.into_iter().sorted().eq(0..NUM_SIGNALS) {
+        return None;
+    }
+
+    pattern.outputs.iter().try_fold(0, |value, o| {
+        let digit = digit(o)?;
+        Some(value * 10 + digit as u32)
+    })
+}
+
+pub fn part_2(patterns: &[Pattern]) -> u32 {
+    patterns.iter().map(|p| p.value).sum()
+}
+
+/// The examples from the puzzle description, with the answers it gives for them.
+pub fn examples() -> Vec<Example> {
+    vec![(
+        "\
+be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe 
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce",
+        Some(Answer::Int(26)),
+        Some(Answer::Int(61229)),
+    )]
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = DAY;
+    type Input = Vec<Pattern>;
+
+    fn parse(input: &str) -> Result<Vec<Pattern>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Vec<Pattern>) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Vec<Pattern>) -> Answer {
+        part_2(input).into()
+    }
+
+    fn examples() -> Vec<Example> {
+        examples()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = parse(examples()[0].0).unwrap();
+
+        assert_eq!(26, part_1(&input));
+        assert_eq!(61229, part_2(&input));
+
+        let err = parse("ab ab ab ab ab ab ab ab ab ab | ab ab ab ab").unwrap_err();
+        assert_eq!((1, 1), (err.line, err.column));
+
+        // the segment counts match the ten digits, but no wiring shows them all
+        let err = parse("cgfd cbdgfe edf fd fgdcba gdceb dgfbe dbagce efbga bdfaceg | fd fd fd fd")
+            .unwrap_err();
+        assert_eq!((1, 1), (err.line, err.column));
+    }
+}
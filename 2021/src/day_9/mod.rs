@@ -0,0 +1,108 @@
+use std::collections::BTreeSet;
+
+use itertools::Itertools;
+
+use crate::{
+    grid::{Grid, Point},
+    Answer, Example, ParseError, Solution,
+};
+
+const DAY: u8 = 9;
+
+type Basin = BTreeSet<Point>;
+
+pub fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse_digits(DAY, input)
+}
+
+fn is_minima(grid: &Grid<u8>, p: Point) -> bool {
+    grid.neighbours_4(p).all(|n| grid[n] > grid[p])
+}
+
+fn minima_coords(grid: &Grid<u8>) -> Vec<Point> {
+    grid.points().filter(|&p| is_minima(grid, p)).collect()
+}
+
+pub fn part_1(grid: &Grid<u8>) -> u32 {
+    minima_coords(grid)
+        .iter()
+        .map(|&p| grid[p] as u32 + 1)
+        .sum()
+}
+
+fn flood_fill(basin: &mut Basin, grid: &Grid<u8>, p: Point) {
+    if basin.insert(p) {
+        for n in grid.neighbours_4(p).filter(|&n| grid[n] < 9) {
+            flood_fill(basin, grid, n);
+        }
+    }
+}
+
+fn basin_size(grid: &Grid<u8>, p: Point) -> usize {
+    let mut basin = Basin::new();
+    flood_fill(&mut basin, grid, p);
+    basin.len()
+}
+
+pub fn part_2(grid: &Grid<u8>) -> usize {
+    minima_coords(grid)
+        .iter()
+        .map(|&p| basin_size(grid, p))
+        .sorted_unstable_by(|a, b| b.cmp(a))
+        .take(3)
+        .product()
+}
+
+/// The examples from the puzzle description, with the answers it gives for them.
+pub fn examples() -> Vec<Example> {
+    vec![(
+        "\
+2199943210
+3987894921
+9856789892
+8767896789
+9899965678",
+        Some(Answer::Int(15)),
+        Some(Answer::Int(1134)),
+    )]
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = DAY;
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(input: &Grid<u8>) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part_2(input: &Grid<u8>) -> Answer {
+        part_2(input).into()
+    }
+
+    fn examples() -> Vec<Example> {
+        examples()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let input = parse(examples()[0].0).unwrap();
+
+        assert_eq!(15, part_1(&input));
+        assert_eq!(1134, part_2(&input));
+
+        // a grid taller than it is wide
+        let input = parse("91\n99\n19").unwrap();
+        assert_eq!(4, part_1(&input));
+    }
+}
@@ -0,0 +1,936 @@
+#[cfg(feature = "day9")]
+use std::collections::VecDeque;
+#[cfg(any(
+    feature = "day1",
+    feature = "day2",
+    feature = "day3",
+    feature = "day4",
+    feature = "day5",
+    feature = "day8",
+    feature = "day13",
+    feature = "day14",
+    feature = "day19",
+    feature = "day22",
+    feature = "day24"
+))]
+use std::fmt::Write;
+
+#[cfg(any(
+    feature = "day3",
+    feature = "day12",
+    feature = "day13",
+    feature = "day19"
+))]
+use rustc_hash::FxHashSet;
+
+#[cfg(feature = "day11")]
+use crate::day_11;
+#[cfg(feature = "day23")]
+use crate::day_23;
+#[cfg(feature = "day25")]
+use crate::day_25;
+#[cfg(any(feature = "day9", feature = "day25"))]
+use crate::grid::Grid;
+pub use aoc_core::rng::Rng;
+
+type Generator = fn(&mut Rng, usize) -> String;
+
+/// Generates a valid input for a day, or returns `None` if there's no such day or it wasn't
+/// compiled in.
+///
+/// The same seed and scale always give the same input. A scale of 1 is about the size of a real
+/// puzzle input, and the size grows linearly with it, except for days 21, 23 and 24 whose inputs
+/// have a fixed size.
+pub fn generate(day: u8, seed: u64, scale: usize) -> Option<String> {
+    // some generators check their inputs with the day's own solution
+    let generator: Option<Generator> = match day {
+        #[cfg(feature = "day1")]
+        1 => Some(day_1),
+        #[cfg(feature = "day2")]
+        2 => Some(day_2),
+        #[cfg(feature = "day3")]
+        3 => Some(day_3),
+        #[cfg(feature = "day4")]
+        4 => Some(day_4),
+        #[cfg(feature = "day5")]
+        5 => Some(day_5),
+        #[cfg(feature = "day6")]
+        6 => Some(day_6),
+        #[cfg(feature = "day7")]
+        7 => Some(day_7),
+        #[cfg(feature = "day8")]
+        8 => Some(day_8),
+        #[cfg(feature = "day9")]
+        9 => Some(day_9),
+        #[cfg(feature = "day10")]
+        10 => Some(day_10),
+        #[cfg(feature = "day11")]
+        11 => Some(day_11),
+        #[cfg(feature = "day12")]
+        12 => Some(day_12),
+        #[cfg(feature = "day13")]
+        13 => Some(day_13),
+        #[cfg(feature = "day14")]
+        14 => Some(day_14),
+        #[cfg(feature = "day15")]
+        15 => Some(day_15),
+        #[cfg(feature = "day16")]
+        16 => Some(day_16),
+        #[cfg(feature = "day17")]
+        17 => Some(day_17),
+        #[cfg(feature = "day18")]
+        18 => Some(day_18),
+        #[cfg(feature = "day19")]
+        19 => Some(day_19),
+        #[cfg(feature = "day20")]
+        20 => Some(day_20),
+        #[cfg(feature = "day21")]
+        21 => Some(day_21),
+        #[cfg(feature = "day22")]
+        22 => Some(day_22),
+        #[cfg(feature = "day23")]
+        23 => Some(day_23),
+        #[cfg(feature = "day24")]
+        24 => Some(day_24),
+        #[cfg(feature = "day25")]
+        25 => Some(day_25),
+        _ => None,
+    };
+    Some(generator?(&mut Rng::new(seed), scale.max(1)))
+}
+
+/// The side of a square with `scale` times the area of a `side` by `side` square.
+#[cfg(any(
+    feature = "day9",
+    feature = "day11",
+    feature = "day15",
+    feature = "day20",
+    feature = "day25"
+))]
+fn scaled_side(side: usize, scale: usize) -> usize {
+    (side as f64 * (scale as f64).sqrt()).round() as usize
+}
+
+#[cfg(any(
+    feature = "day11",
+    feature = "day15",
+    feature = "day20",
+    feature = "day25"
+))]
+fn grid(rng: &mut Rng, side: usize, mut cell: impl FnMut(&mut Rng) -> char) -> String {
+    let mut out = String::with_capacity(side * (side + 1));
+    for _ in 0..side {
+        out.extend((0..side).map(|_| cell(rng)));
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(any(
+    feature = "day9",
+    feature = "day11",
+    feature = "day15",
+    feature = "day18"
+))]
+fn digit_char(n: usize) -> char {
+    char::from_digit(n as u32, 10).unwrap()
+}
+
+#[cfg(any(feature = "day11", feature = "day15", feature = "day18"))]
+fn digit(rng: &mut Rng, lo: u32, hi: u32) -> char {
+    digit_char(rng.between(lo.into(), hi.into()) as usize)
+}
+
+/// Sonar depths that drift deeper.
+#[cfg(feature = "day1")]
+fn day_1(rng: &mut Rng, scale: usize) -> String {
+    let mut out = String::new();
+    let mut depth = rng.between(100, 200);
+    for _ in 0..2000 * scale {
+        depth = (depth + rng.between(-10, 20)).max(0);
+        writeln!(out, "{}", depth).unwrap();
+    }
+    out
+}
+
+/// Submarine commands that never aim above the surface.
+#[cfg(feature = "day2")]
+fn day_2(rng: &mut Rng, scale: usize) -> String {
+    let mut out = String::new();
+    let mut aim = 0;
+    for _ in 0..1000 * scale {
+        let n = rng.between(1, 9);
+        let command = match rng.below(3) {
+            0 => "forward",
+            1 if aim >= n => {
+                aim -= n;
+                "up"
+            }
+            _ => {
+                aim += n;
+                "down"
+            }
+        };
+        writeln!(out, "{} {}", command, n).unwrap();
+    }
+    out
+}
+
+/// Distinct binary numbers, with more bits once there are too many to fit in 12.
+#[cfg(feature = "day3")]
+fn day_3(rng: &mut Rng, scale: usize) -> String {
+    let count = 1000 * scale;
+    let bits = (usize::BITS - (4 * count).leading_zeros()).clamp(12, 32);
+    let count = count.min(1 << (bits - 1));
+
+    let mut seen = FxHashSet::default();
+    let mut out = String::new();
+    while seen.len() < count {
+        let n = rng.next_u64() >> (64 - bits);
+        if seen.insert(n) {
+            writeln!(out, "{:0width$b}", n, width = bits as usize).unwrap();
+        }
+    }
+    out
+}
+
+/// Draws every number from 0 to 99, so that every board wins eventually, and only one board
+/// wins last.
+#[cfg(feature = "day4")]
+fn day_4(rng: &mut Rng, scale: usize) -> String {
+    let mut nums: Vec<_> = (0..100).collect();
+    rng.shuffle(&mut nums);
+    let mut drawn_at = [0; 100];
+    for (i, &n) in nums.iter().enumerate() {
+        drawn_at[n] = i;
+    }
+    // a board wins once every number in one of its rows or columns has been drawn
+    let wins_at = |board: &[usize]| {
+        let rows = (0..5).map(|y| (0..5).map(|x| drawn_at[board[5 * y + x]]).max());
+        let columns = (0..5).map(|x| (0..5).map(|y| drawn_at[board[5 * y + x]]).max());
+        rows.chain(columns).flatten().min().unwrap()
+    };
+    let board = |rng: &mut Rng| {
+        let mut board = nums.clone();
+        rng.shuffle(&mut board);
+        board.truncate(25);
+        board
+    };
+
+    let mut boards: Vec<_> = (0..100 * scale).map(|_| board(rng)).collect();
+    let last = boards.iter().map(|b| wins_at(b)).max().unwrap();
+    for tied in boards.iter_mut().filter(|b| wins_at(b) == last).skip(1) {
+        while wins_at(tied) >= last {
+            *tied = board(rng);
+        }
+    }
+
+    let mut out = nums
+        .iter()
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    out.push('\n');
+    for board in boards {
+        out.push('\n');
+        for row in board.chunks(5) {
+            let row: Vec<_> = row.iter().map(|n| format!("{:>2}", n)).collect();
+            writeln!(out, "{}", row.join(" ")).unwrap();
+        }
+    }
+    out
+}
+
+/// Horizontal, vertical and diagonal lines in a 1000 by 1000 area.
+#[cfg(feature = "day5")]
+fn day_5(rng: &mut Rng, scale: usize) -> String {
+    const MAX: i64 = 989;
+    let mut out = String::new();
+    for _ in 0..500 * scale {
+        let (x1, y1) = (rng.between(0, MAX), rng.between(0, MAX));
+        // head away from the nearest edges, so there's always room for a long line
+        let dx = if x1 < MAX / 2 { 1 } else { -1 };
+        let dy = if y1 < MAX / 2 { 1 } else { -1 };
+        let len = rng.between(1, 300);
+        let (x2, y2) = match rng.below(3) {
+            0 => (x1 + dx * len, y1),
+            1 => (x1, y1 + dy * len),
+            _ => (x1 + dx * len, y1 + dy * len),
+        };
+        writeln!(out, "{},{} -> {},{}", x1, y1, x2, y2).unwrap();
+    }
+    out
+}
+
+/// The ages of a shoal of lanternfish.
+#[cfg(feature = "day6")]
+fn day_6(rng: &mut Rng, scale: usize) -> String {
+    let ages: Vec<_> = (0..300 * scale)
+        .map(|_| rng.between(1, 5).to_string())
+        .collect();
+    ages.join(",") + "\n"
+}
+
+/// The positions of a swarm of crabs.
+#[cfg(feature = "day7")]
+fn day_7(rng: &mut Rng, scale: usize) -> String {
+    let positions: Vec<_> = (0..1000 * scale)
+        .map(|_| rng.below(2000).to_string())
+        .collect();
+    positions.join(",") + "\n"
+}
+
+/// Displays with randomly crossed wires.
+#[cfg(feature = "day8")]
+fn day_8(rng: &mut Rng, scale: usize) -> String {
+    const DIGITS: [&str; 10] = [
+        "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+    ];
+    let mut out = String::new();
+    for _ in 0..200 * scale {
+        let mut wires = *b"abcdefg";
+        rng.shuffle(&mut wires);
+        let pattern = |rng: &mut Rng, digit: &str| {
+            let mut segments: Vec<_> = digit.bytes().map(|b| wires[(b - b'a') as usize]).collect();
+            rng.shuffle(&mut segments);
+            String::from_utf8(segments).unwrap()
+        };
+
+        let mut signals = DIGITS;
+        rng.shuffle(&mut signals);
+        let signals: Vec<_> = signals.iter().map(|d| pattern(rng, d)).collect();
+        let outputs: Vec<_> = (0..4)
+            .map(|_| {
+                let digit = rng.choose(&DIGITS);
+                pattern(rng, digit)
+            })
+            .collect();
+        writeln!(out, "{} | {}", signals.join(" "), outputs.join(" ")).unwrap();
+    }
+    out
+}
+
+/// A square heightmap of basins walled off by 9s, each sloping down to a low point.
+#[cfg(feature = "day9")]
+fn day_9(rng: &mut Rng, scale: usize) -> String {
+    let side = scaled_side(100, scale);
+    let mut heights = Grid::new(side, side, vec![None; side * side]);
+
+    // grow the basins outwards from their low points all at once, so each ends up with the
+    // points closest to it
+    let mut queue = VecDeque::new();
+    for basin in 0..side * side / 100 {
+        let p = (rng.below(side), rng.below(side));
+        if heights[p].is_none() {
+            heights[p] = Some((basin, 0));
+            queue.push_back(p);
+        }
+    }
+    while let Some(p) = queue.pop_front() {
+        let (basin, height) = heights[p].unwrap();
+        for n in heights.neighbours_4(p).collect::<Vec<_>>() {
+            if heights[n].is_none() {
+                heights[n] = Some((basin, height + 1));
+                queue.push_back(n);
+            }
+        }
+    }
+
+    let mut out = String::with_capacity(side * (side + 1));
+    for y in 0..side {
+        for x in 0..side {
+            let (basin, height) = heights[(x, y)].unwrap();
+            // walling off just one side of each edge keeps the walls thin
+            let edge = heights
+                .neighbours_4((x, y))
+                .any(|n| heights[n].unwrap().0 < basin);
+            out.push(if edge { '9' } else { digit_char(height.min(8)) });
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Navigation lines, about half of which are corrupted and the rest incomplete.
+#[cfg(feature = "day10")]
+fn day_10(rng: &mut Rng, scale: usize) -> String {
+    const OPEN: [char; 4] = ['(', '[', '{', '<'];
+    const CLOSE: [char; 4] = [')', ']', '}', '>'];
+    // keeps the completion scores well within 64 bits, like the real inputs
+    const MAX_UNCLOSED: usize = 20;
+    let mut out = String::new();
+    for _ in 0..100 * scale {
+        let len = rng.below(30) + 80;
+        let corrupt_at = rng.chance(0.5).then(|| rng.below(len));
+        let mut stack = vec![];
+        for i in 0..len {
+            if Some(i) == corrupt_at {
+                let expected = stack.last().copied();
+                let wrong: Vec<_> = (0..4).filter(|&c| Some(c) != expected).collect();
+                out.push(CLOSE[*rng.choose(&wrong)]);
+            } else if stack.is_empty() || (stack.len() < MAX_UNCLOSED && rng.chance(0.6)) {
+                let c = rng.below(4);
+                stack.push(c);
+                out.push(OPEN[c]);
+            } else {
+                out.push(CLOSE[stack.pop().unwrap()]);
+            }
+        }
+        if stack.is_empty() {
+            out.push(OPEN[rng.below(4)]);
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// A square grid of octopuses that all flash at once within 1000 steps.
+#[cfg(feature = "day11")]
+fn day_11(rng: &mut Rng, scale: usize) -> String {
+    let side = scaled_side(10, scale);
+    let (mut spread, mut attempts) = (9, 0);
+    loop {
+        let lo = rng.between(0, 9 - spread) as u32;
+        let input = grid(rng, side, |rng| digit(rng, lo, lo + spread as u32));
+        let mut octopuses = day_11::parse(&input).unwrap();
+        if (0..1000).any(|_| day_11::generation(&mut octopuses) == octopuses.len() as u64) {
+            return input;
+        }
+        // few random grids ever synchronize, and bigger ones even less often, but energy levels
+        // closer together soon start flashing together
+        attempts += 1;
+        if attempts % 3 == 0 {
+            spread = (spread - 1).max(3);
+        }
+    }
+}
+
+/// A connected cave system in which no two big caves are next to each other.
+///
+/// It's made of separate clusters of about a real input's size, which only share `start` and
+/// `end`. No path can go from one to another, since it can't go back through `start` and stops at
+/// `end`, so the number of paths grows linearly with the number of clusters, rather than
+/// exponentially with the number of caves. The solution only allows 256 caves, so there are at
+/// most 24 clusters.
+#[cfg(feature = "day12")]
+fn day_12(rng: &mut Rng, scale: usize) -> String {
+    let name = |mut i: usize, big: bool| {
+        let base = if big { b'A' } else { b'a' };
+        let mut name = String::new();
+        loop {
+            name.insert(0, (base + (i % 26) as u8) as char);
+            i /= 26;
+            if name.len() >= 2 && i == 0 {
+                return name;
+            }
+        }
+    };
+    const START: usize = 0;
+    const END: usize = 1;
+    let mut caves = vec![("start".to_string(), false), ("end".to_string(), false)];
+    let mut edges = FxHashSet::default();
+    let mut out = String::new();
+    let mut connect = |rng: &mut Rng, caves: &[(String, bool)], a: usize, b: usize| {
+        if a != b && !(caves[a].1 && caves[b].1) && edges.insert((a.min(b), a.max(b))) {
+            let (a, b) = if rng.chance(0.5) { (a, b) } else { (b, a) };
+            out += &format!("{}-{}\n", caves[a].0, caves[b].0);
+            true
+        } else {
+            false
+        }
+    };
+
+    let (small, big) = (6, 3);
+    for cluster in 0..scale.clamp(1, 24) {
+        let mut ids: Vec<_> = (caves.len()..caves.len() + small + big).collect();
+        caves.extend((0..small).map(|i| (name(cluster * small + i, false), false)));
+        caves.extend((0..big).map(|i| (name(cluster * big + i, true), true)));
+        rng.shuffle(&mut ids);
+        ids.insert(0, START);
+        // after at least one of the cluster's own caves, so there's always a new edge for it
+        ids.insert(2 + rng.below(ids.len() - 1), END);
+
+        for i in 1..ids.len() {
+            // joining each cave to an earlier one keeps everything connected
+            loop {
+                let earlier = ids[rng.below(i)];
+                if connect(rng, &caves, ids[i], earlier) {
+                    break;
+                }
+            }
+        }
+        // big caves are where the paths multiply, since they can be passed through again and
+        // again, so each only gets a few more neighbours
+        for &a in ids.iter().filter(|&&id| caves[id].1) {
+            for _ in 0..3 {
+                let b = *rng.choose(&ids);
+                connect(rng, &caves, a, b);
+            }
+        }
+        for _ in 0..ids.len() / 3 {
+            let (a, b) = (*rng.choose(&ids), *rng.choose(&ids));
+            connect(rng, &caves, a, b);
+        }
+    }
+    out
+}
+
+/// Dots that fold down into a random pattern in a 40 by 6 area, with the folds alternating like
+/// in the puzzle.
+#[cfg(feature = "day13")]
+fn day_13(rng: &mut Rng, scale: usize) -> String {
+    const FOLDS: [(char, i64); 12] = [
+        ('x', 655),
+        ('y', 447),
+        ('x', 327),
+        ('y', 223),
+        ('x', 163),
+        ('y', 111),
+        ('x', 81),
+        ('y', 55),
+        ('x', 40),
+        ('y', 27),
+        ('y', 13),
+        ('y', 6),
+    ];
+    let pattern: Vec<_> = (0..40)
+        .flat_map(|x| (0..6).map(move |y| (x, y)))
+        .filter(|_| rng.chance(0.4))
+        .collect();
+    // each dot in the pattern can only be unfolded to so many places
+    let count = (900 * scale).min(pattern.len() << (FOLDS.len() - 1));
+
+    let mut dots = FxHashSet::default();
+    let mut out = String::new();
+    while dots.len() < count {
+        let (mut x, mut y) = *rng.choose(&pattern);
+        // unfolding in reverse mirrors each dot to either side of the fold
+        for (axis, at) in FOLDS.iter().rev() {
+            let coord = if *axis == 'x' { &mut x } else { &mut y };
+            if rng.chance(0.5) {
+                *coord = 2 * at - *coord;
+            }
+        }
+        if dots.insert((x, y)) {
+            writeln!(out, "{},{}", x, y).unwrap();
+        }
+    }
+    out.push('\n');
+    for (axis, at) in FOLDS {
+        writeln!(out, "fold along {}={}", axis, at).unwrap();
+    }
+    out
+}
+
+/// A polymer template with an insertion rule for every pair of ten elements.
+#[cfg(feature = "day14")]
+fn day_14(rng: &mut Rng, scale: usize) -> String {
+    const ELEMENTS: &[u8] = b"BCFHKNOPSV";
+    let template: Vec<_> = (0..20 * scale).map(|_| *rng.choose(ELEMENTS)).collect();
+    let mut out = String::from_utf8(template).unwrap() + "\n\n";
+    for &a in ELEMENTS {
+        for &b in ELEMENTS {
+            let to = *rng.choose(ELEMENTS);
+            writeln!(out, "{}{} -> {}", a as char, b as char, to as char).unwrap();
+        }
+    }
+    out
+}
+
+/// A square map of risk levels.
+#[cfg(feature = "day15")]
+fn day_15(rng: &mut Rng, scale: usize) -> String {
+    grid(rng, scaled_side(100, scale), |rng| digit(rng, 1, 9))
+}
+
+/// Values are kept well below `u64::MAX` so that sums and products can't overflow.
+#[cfg(feature = "day16")]
+const MAX_PACKET_VALUE: u64 = 1 << 48;
+
+#[cfg(feature = "day16")]
+fn push_bits(bits: &mut Vec<bool>, value: u64, len: usize) {
+    bits.extend((0..len).rev().map(|i| value >> i & 1 == 1));
+}
+
+/// Generates a packet containing `size` packets in total, returning its bits and value.
+#[cfg(feature = "day16")]
+fn packet(rng: &mut Rng, size: usize) -> (Vec<bool>, u64) {
+    let mut bits = vec![];
+    push_bits(&mut bits, rng.below(8) as u64, 3);
+    if size <= 1 {
+        let value = rng.next_u64() >> *rng.choose(&[28, 52, 56, 60]);
+        push_bits(&mut bits, 4, 3);
+        let groups = (u64::BITS - value.leading_zeros()).max(1).div_ceil(4);
+        for group in (0..groups).rev() {
+            push_bits(&mut bits, (group > 0) as u64, 1);
+            push_bits(&mut bits, value >> (4 * group) & 0xf, 4);
+        }
+        return (bits, value);
+    }
+
+    let comparison = size >= 3 && rng.chance(0.2);
+    let count = if comparison {
+        2
+    } else {
+        // plenty of single subpackets make for deeply nested packets
+        rng.below((size - 1).min(5)) + 1
+    };
+    // split the rest of the packets between the subpackets
+    let mut cuts: Vec<_> = (1..count).map(|_| rng.below(size - count)).collect();
+    cuts.extend([0, size - 1 - count]);
+    cuts.sort_unstable();
+    let subpackets: Vec<_> = cuts
+        .windows(2)
+        .map(|w| packet(rng, w[1] - w[0] + 1))
+        .collect();
+
+    let values: Vec<_> = subpackets.iter().map(|(_, value)| *value).collect();
+    let (id, value) = if comparison {
+        match rng.below(3) {
+            0 => (5, (values[0] > values[1]) as u64),
+            1 => (6, (values[0] < values[1]) as u64),
+            _ => (7, (values[0] == values[1]) as u64),
+        }
+    } else {
+        let sum = values.iter().try_fold(0u64, |a, &b| a.checked_add(b));
+        let product = values.iter().try_fold(1u64, |a, &b| a.checked_mul(b));
+        match (rng.below(4), sum, product) {
+            (0, Some(sum), _) if sum <= MAX_PACKET_VALUE => (0, sum),
+            (1, _, Some(product)) if product <= MAX_PACKET_VALUE => (1, product),
+            (2, _, _) => (2, *values.iter().min().unwrap()),
+            _ => (3, *values.iter().max().unwrap()),
+        }
+    };
+
+    push_bits(&mut bits, id, 3);
+    let contents: Vec<_> = subpackets.into_iter().flat_map(|(bits, _)| bits).collect();
+    if contents.len() < 1 << 15 && rng.chance(0.5) {
+        push_bits(&mut bits, 0, 1);
+        push_bits(&mut bits, contents.len() as u64, 15);
+    } else {
+        push_bits(&mut bits, 1, 1);
+        push_bits(&mut bits, count as u64, 11);
+    }
+    bits.extend(contents);
+    (bits, value)
+}
+
+/// A transmission of nested packets, with about 300 packets per unit of scale.
+#[cfg(feature = "day16")]
+fn day_16(rng: &mut Rng, scale: usize) -> String {
+    let (bits, _) = packet(rng, 300 * scale);
+    let mut out: String = bits
+        .chunks(4)
+        .map(|nibble| {
+            let n = (0..4).fold(0, |n, i| n << 1 | *nibble.get(i).unwrap_or(&false) as u32);
+            char::from_digit(n, 16).unwrap().to_ascii_uppercase()
+        })
+        .collect();
+    out.push('\n');
+    out
+}
+
+/// A target area below and to the right of the probe, growing with the scale.
+///
+/// A probe dropping straight down from its highest point always lands in it, as in the puzzle.
+#[cfg(feature = "day17")]
+fn day_17(rng: &mut Rng, scale: usize) -> String {
+    let scale = scale as i64;
+    let x_start = rng.between(20, 150) * scale;
+    let mut x_end = x_start + rng.between(10, 50) * scale;
+    let mut steps = 1;
+    while steps * (steps + 1) / 2 < x_start {
+        steps += 1;
+    }
+    x_end = x_end.max(steps * (steps + 1) / 2);
+    let y_end = -rng.between(5, 100) * scale;
+    let y_start = y_end - rng.between(5, 50) * scale;
+    format!(
+        "target area: x={}..{}, y={}..{}\n",
+        x_start, x_end, y_start, y_end
+    )
+}
+
+#[cfg(feature = "day18")]
+fn snailfish_pair(rng: &mut Rng, depth: u8, out: &mut String) {
+    out.push('[');
+    for i in 0..2 {
+        if i == 1 {
+            out.push(',');
+        }
+        if depth < 4 && rng.chance(0.6) {
+            snailfish_pair(rng, depth + 1, out);
+        } else {
+            out.push(digit(rng, 0, 9));
+        }
+    }
+    out.push(']');
+}
+
+/// Snailfish numbers that are already reduced.
+#[cfg(feature = "day18")]
+fn day_18(rng: &mut Rng, scale: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..100 * scale {
+        snailfish_pair(rng, 1, &mut out);
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(feature = "day19")]
+type Position = [i64; 3];
+
+/// Scanners see beacons up to this far away on every axis.
+#[cfg(feature = "day19")]
+const SCANNER_RANGE: i64 = 1000;
+
+/// Whether a beacon is in range of a scanner.
+///
+/// Scanners are always at odd coordinates and beacons at even ones, so none are exactly at the
+/// edge of the range.
+#[cfg(feature = "day19")]
+fn in_range(scanner: &Position, beacon: &Position) -> bool {
+    (0..3).all(|i| (beacon[i] - scanner[i]).abs() < SCANNER_RANGE)
+}
+
+#[cfg(feature = "day19")]
+fn even_between(rng: &mut Rng, lo: i64, hi: i64) -> i64 {
+    2 * rng.between((lo + 1).div_euclid(2), hi.div_euclid(2))
+}
+
+/// Scanners that each share at least 12 beacons with one placed before them, in random
+/// orientations.
+#[cfg(feature = "day19")]
+fn day_19(rng: &mut Rng, scale: usize) -> String {
+    // the solution stores coordinates as i16, so keep everything well within that
+    const MAX_COORD: i64 = 12001;
+
+    let mut scanners: Vec<Position> = vec![[1, 1, 1]];
+    let mut beacons = vec![];
+    let mut seen = FxHashSet::default();
+    let mut add_beacon =
+        |rng: &mut Rng, beacons: &mut Vec<Position>, lo: Position, hi: Position| {
+            let beacon = [0, 1, 2].map(|i| even_between(rng, lo[i], hi[i]));
+            if seen.insert(beacon) {
+                beacons.push(beacon);
+            }
+        };
+    let near = |s: &Position| s.map(|c| c - SCANNER_RANGE + 1);
+    let far = |s: &Position| s.map(|c| c + SCANNER_RANGE - 1);
+    for _ in 0..26 {
+        add_beacon(rng, &mut beacons, near(&scanners[0]), far(&scanners[0]));
+    }
+
+    while scanners.len() < 30 * scale {
+        let parent = *rng.choose(&scanners);
+        let scanner = parent.map(|c| c + even_between(rng, -1200, 1200));
+        if scanner.iter().any(|c| c.abs() > MAX_COORD) {
+            continue;
+        }
+
+        let (lo, hi) = (
+            [0, 1, 2].map(|i| near(&parent)[i].max(near(&scanner)[i])),
+            [0, 1, 2].map(|i| far(&parent)[i].min(far(&scanner)[i])),
+        );
+        let shared = |beacons: &[Position]| {
+            let both = |b: &&Position| in_range(&parent, b) && in_range(&scanner, b);
+            beacons.iter().filter(both).count()
+        };
+        while shared(&beacons) < 12 {
+            add_beacon(rng, &mut beacons, lo, hi);
+        }
+        let seen_by =
+            |beacons: &[Position]| beacons.iter().filter(|b| in_range(&scanner, b)).count();
+        while seen_by(&beacons) < 26 {
+            add_beacon(rng, &mut beacons, near(&scanner), far(&scanner));
+        }
+        scanners.push(scanner);
+    }
+
+    let mut out = String::new();
+    for (i, scanner) in scanners.iter().enumerate() {
+        let rotation = random_rotation(rng);
+        let mut seen: Vec<_> = beacons.iter().filter(|b| in_range(scanner, b)).collect();
+        rng.shuffle(&mut seen);
+        if i > 0 {
+            out.push('\n');
+        }
+        writeln!(out, "--- scanner {} ---", i).unwrap();
+        for beacon in seen {
+            let [x, y, z] = rotation(&[0, 1, 2].map(|i| beacon[i] - scanner[i]));
+            writeln!(out, "{},{},{}", x, y, z).unwrap();
+        }
+    }
+    out
+}
+
+/// One of the 24 ways to turn a scanner, as a function from world to scanner coordinates.
+#[cfg(feature = "day19")]
+fn random_rotation(rng: &mut Rng) -> impl Fn(&Position) -> Position {
+    let mut axes = [0, 1, 2];
+    rng.shuffle(&mut axes);
+    let mut signs = [0; 3].map(|_| if rng.chance(0.5) { 1 } else { -1 });
+    // an odd permutation or an odd number of flips is a reflection, and two make a rotation
+    let even = (axes[0] + 1) % 3 == axes[1];
+    if even != (signs.iter().product::<i64>() == 1) {
+        signs[2] = -signs[2];
+    }
+    move |p| [0, 1, 2].map(|i| signs[i] * p[axes[i]])
+}
+
+/// A square image enhanced by a random algorithm, which never lights up the infinite image for
+/// good.
+#[cfg(feature = "day20")]
+fn day_20(rng: &mut Rng, scale: usize) -> String {
+    let pixel = |rng: &mut Rng| if rng.chance(0.5) { '#' } else { '.' };
+    let mut lookup: Vec<_> = (0..512).map(|_| pixel(rng)).collect();
+    if lookup[0] == '#' {
+        lookup[511] = '.';
+    }
+    let mut out: String = lookup.into_iter().collect();
+    out += "\n\n";
+    out += &grid(rng, scaled_side(100, scale), pixel);
+    out
+}
+
+/// Two starting positions, which don't depend on the scale.
+#[cfg(feature = "day21")]
+fn day_21(rng: &mut Rng, _: usize) -> String {
+    format!(
+        "Player 1 starting position: {}\nPlayer 2 starting position: {}\n",
+        rng.between(1, 10),
+        rng.between(1, 10)
+    )
+}
+
+/// Reboot steps, starting with 20 inside the initialization area and then many more outside it.
+#[cfg(feature = "day22")]
+fn day_22(rng: &mut Rng, scale: usize) -> String {
+    let mut out = String::new();
+    for i in 0..420 * scale {
+        let (lo, max_len) = if i < 20 {
+            (-50, 50)
+        } else {
+            (-100_000, 50_000)
+        };
+        let ranges = loop {
+            let ranges = [0; 3].map(|_| {
+                let start = rng.between(lo, -lo - max_len);
+                (start, start + rng.between(max_len / 5, max_len))
+            });
+            // only the first steps touch the initialization area, as in the puzzle
+            if i < 20 || ranges.iter().any(|&(start, end)| end < -50 || start > 50) {
+                break ranges;
+            }
+        };
+        let ranges: Vec<_> = ["x", "y", "z"]
+            .iter()
+            .zip(ranges)
+            .map(|(axis, (start, end))| format!("{}={}..{}", axis, start, end))
+            .collect();
+        let action = if i == 0 || rng.chance(0.6) {
+            "on"
+        } else {
+            "off"
+        };
+        writeln!(out, "{} {}", action, ranges.join(",")).unwrap();
+    }
+    out
+}
+
+/// A shuffled burrow that can be solved in both parts, which doesn't depend on the scale.
+#[cfg(feature = "day23")]
+fn day_23(rng: &mut Rng, _: usize) -> String {
+    let row = |pods: &[u8]| {
+        let pods: Vec<_> = pods.iter().map(|&b| (b as char).to_string()).collect();
+        pods.join("#")
+    };
+    loop {
+        let mut pods = *b"AABBCCDD";
+        rng.shuffle(&mut pods);
+        let input = format!(
+            "#############\n#...........#\n###{}###\n  #{}#\n  #########\n",
+            row(&pods[..4]),
+            row(&pods[4..])
+        );
+        if day_23::is_solvable(&day_23::parse(&input).unwrap()) {
+            return input;
+        }
+    }
+}
+
+/// A MONAD program for which some model number is valid, which doesn't depend on the scale.
+#[cfg(feature = "day24")]
+fn day_24(rng: &mut Rng, _: usize) -> String {
+    const DIGITS: usize = 14;
+    let mut out = String::new();
+    let mut stack = vec![];
+    let mut pushes = 0;
+    for _ in 0..DIGITS {
+        let c = rng.between(1, 16);
+        let must_pop = pushes == DIGITS / 2;
+        let (a, b) = if stack.is_empty() || (!must_pop && rng.chance(0.5)) {
+            pushes += 1;
+            stack.push(c);
+            (1, rng.between(10, 16))
+        } else {
+            // keeping the two digits within 8 of each other is what makes a solution possible
+            let pushed = stack.pop().unwrap();
+            (26, rng.between(-8, pushed.min(8)) - pushed)
+        };
+        writeln!(
+            out,
+            "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\neql x w\neql x 0\nmul y 0\n\
+             add y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\nadd y {}\nmul y x\nadd z y",
+            a, b, c
+        )
+        .unwrap();
+    }
+    out
+}
+
+/// A square grid of sea cucumbers that stop moving within 20 steps per cell along a side.
+#[cfg(feature = "day25")]
+fn day_25(rng: &mut Rng, scale: usize) -> String {
+    let side = scaled_side(140, scale);
+    loop {
+        let input = grid(rng, side, |rng| match rng.below(20) {
+            0..=5 => '>',
+            6..=11 => 'v',
+            _ => '.',
+        });
+        let mut cucumbers: Grid<u8> = day_25::parse(&input).unwrap();
+        if (0..20 * side).any(|_| !day_25::step(&mut cucumbers)) {
+            return input;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{solution, Part, SOLUTIONS};
+
+    #[test]
+    fn test() {
+        assert_eq!(None, generate(0, 1, 1));
+        assert_eq!(None, generate(26, 1, 1));
+        for day in SOLUTIONS.iter().map(|s| s.day()) {
+            let input = generate(day, 1, 1).unwrap();
+            assert_eq!(Some(&input), generate(day, 1, 1).as_ref());
+            assert!((2..5).any(|seed| generate(day, seed, 1).unwrap() != input));
+            let parsed = solution(day).unwrap().parse_input(&input);
+            assert!(parsed.is_ok(), "day {}: {}", day, parsed.err().unwrap());
+        }
+
+        // these are the days where a careless input could make a solution panic or never finish
+        let careless = [4, 10, 11, 16, 17, 23, 24, 25];
+        for day in careless.into_iter().filter(|&day| solution(day).is_some()) {
+            let input = generate(day, 3, 1).unwrap();
+            for part in Part::ALL {
+                assert!(solution(day).unwrap().run(part, &input).is_ok());
+            }
+        }
+    }
+}
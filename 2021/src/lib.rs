@@ -0,0 +1,175 @@
+#[cfg(feature = "day1")]
+pub mod day_1;
+#[cfg(feature = "day10")]
+pub mod day_10;
+#[cfg(feature = "day11")]
+pub mod day_11;
+#[cfg(feature = "day12")]
+pub mod day_12;
+#[cfg(feature = "day13")]
+pub mod day_13;
+#[cfg(feature = "day14")]
+pub mod day_14;
+#[cfg(feature = "day15")]
+pub mod day_15;
+#[cfg(feature = "day16")]
+pub mod day_16;
+#[cfg(feature = "day17")]
+pub mod day_17;
+#[cfg(feature = "day18")]
+pub mod day_18;
+#[cfg(feature = "day19")]
+pub mod day_19;
+#[cfg(feature = "day2")]
+pub mod day_2;
+#[cfg(feature = "day20")]
+pub mod day_20;
+#[cfg(feature = "day21")]
+pub mod day_21;
+#[cfg(feature = "day22")]
+pub mod day_22;
+#[cfg(feature = "day23")]
+pub mod day_23;
+#[cfg(feature = "day24")]
+pub mod day_24;
+#[cfg(feature = "day25")]
+pub mod day_25;
+#[cfg(feature = "day3")]
+pub mod day_3;
+#[cfg(feature = "day4")]
+pub mod day_4;
+#[cfg(feature = "day5")]
+pub mod day_5;
+#[cfg(feature = "day6")]
+pub mod day_6;
+#[cfg(feature = "day7")]
+pub mod day_7;
+#[cfg(feature = "day8")]
+pub mod day_8;
+#[cfg(feature = "day9")]
+pub mod day_9;
+pub mod generate;
+pub mod reference;
+
+pub use aoc_core::{
+    cancel, grid, num, params, parse, trace, Answer, DynSolution, Example, Param, Params,
+    ParseError, ParsedInput, Part, Solution, Year,
+};
+
+/// Every day's solution that was compiled in, in order.
+pub static SOLUTIONS: &[&dyn DynSolution] = &[
+    #[cfg(feature = "day1")]
+    &day_1::Day1,
+    #[cfg(feature = "day2")]
+    &day_2::Day2,
+    #[cfg(feature = "day3")]
+    &day_3::Day3,
+    #[cfg(feature = "day4")]
+    &day_4::Day4,
+    #[cfg(feature = "day5")]
+    &day_5::Day5,
+    #[cfg(feature = "day6")]
+    &day_6::Day6,
+    #[cfg(feature = "day7")]
+    &day_7::Day7,
+    #[cfg(feature = "day8")]
+    &day_8::Day8,
+    #[cfg(feature = "day9")]
+    &day_9::Day9,
+    #[cfg(feature = "day10")]
+    &day_10::Day10,
+    #[cfg(feature = "day11")]
+    &day_11::Day11,
+    #[cfg(feature = "day12")]
+    &day_12::Day12,
+    #[cfg(feature = "day13")]
+    &day_13::Day13,
+    #[cfg(feature = "day14")]
+    &day_14::Day14,
+    #[cfg(feature = "day15")]
+    &day_15::Day15,
+    #[cfg(feature = "day16")]
+    &day_16::Day16,
+    #[cfg(feature = "day17")]
+    &day_17::Day17,
+    #[cfg(feature = "day18")]
+    &day_18::Day18,
+    #[cfg(feature = "day19")]
+    &day_19::Day19,
+    #[cfg(feature = "day20")]
+    &day_20::Day20,
+    #[cfg(feature = "day21")]
+    &day_21::Day21,
+    #[cfg(feature = "day22")]
+    &day_22::Day22,
+    #[cfg(feature = "day23")]
+    &day_23::Day23,
+    #[cfg(feature = "day24")]
+    &day_24::Day24,
+    #[cfg(feature = "day25")]
+    &day_25::Day25,
+];
+
+/// Looks up the solution for a day, numbered from 1, if it was compiled in.
+pub fn solution(day: u8) -> Option<&'static dyn DynSolution> {
+    YEAR.solution(day)
+}
+
+/// This year's puzzles, for registering with [`aoc_core::runner`].
+pub static YEAR: Year = Year {
+    year: 2021,
+    solutions: SOLUTIONS,
+    generate: generate::generate,
+    source_dir: concat!(env!("CARGO_MANIFEST_DIR"), "/src"),
+};
+
+#[cfg(all(test, feature = "all"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        assert_eq!(25, SOLUTIONS.len());
+        for (i, s) in SOLUTIONS.iter().enumerate() {
+            assert_eq!(i + 1, s.day() as usize);
+        }
+        assert!(solution(0).is_none());
+        assert_eq!(2021, YEAR.year);
+        assert_eq!(
+            Ok(Answer::Int(7)),
+            solution(1)
+                .unwrap()
+                .run(Part::One, "1\n2\n3\n4\n5\n6\n7\n8")
+        );
+        assert_eq!(Ok(Answer::None), solution(25).unwrap().run(Part::Two, ">."));
+        assert_eq!(
+            3,
+            solution(1)
+                .unwrap()
+                .run(Part::One, "1\n2\nx")
+                .unwrap_err()
+                .line
+        );
+        assert_eq!("", Answer::None.to_string());
+
+        for s in SOLUTIONS {
+            for (i, (input, part_1, part_2)) in s.examples().into_iter().enumerate() {
+                for (part, expected) in Part::ALL.into_iter().zip([part_1, part_2]) {
+                    let Some(expected) = expected else { continue };
+                    let got = s.run(part, input).unwrap();
+                    assert_eq!(
+                        expected,
+                        got,
+                        "day {} example {} part {}",
+                        s.day(),
+                        i + 1,
+                        part
+                    );
+                }
+            }
+        }
+        assert!(SOLUTIONS
+            .iter()
+            .all(|s| s.day() == 24 || !s.examples().is_empty()));
+    }
+}
@@ -0,0 +1,372 @@
+//! Slow but obviously correct versions of the days whose solutions rely on shortcuts, so the
+//! real solutions can be checked against them.
+//!
+//! These parse their input loosely and panic if it is malformed, since they're only meant to be
+//! fed inputs that the real solutions accept.
+
+use itertools::Itertools;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+fn numbers<T: std::str::FromStr>(text: &str) -> Vec<T> {
+    text.split(|c: char| !c.is_ascii_digit() && c != '-')
+        .filter(|s| !s.is_empty() && *s != "-")
+        .map(|s| {
+            s.parse()
+                .unwrap_or_else(|_| panic!("expected a number, not {:?}", s))
+        })
+        .collect()
+}
+
+/// The sum of the output values (part 2 of day 8), found by trying every wiring of the segments,
+/// or `None` if some line can't be wired up to show all ten digits.
+pub fn day_8(input: &str) -> Option<u32> {
+    const DIGITS: [&str; 10] = [
+        "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+    ];
+
+    // each digit as a set of lit segments, with a bit for each segment
+    let segments: Vec<u8> = (0..7).map(|i| 1 << i).collect();
+    let lit = |digit: &str| {
+        digit
+            .bytes()
+            .fold(0, |lit, b| lit | segments[(b - b'a') as usize])
+    };
+    let digits = DIGITS.map(lit);
+
+    let mut total = 0;
+    for line in input.lines().filter(|line| !line.trim().is_empty()) {
+        let (signals, outputs) = line.split_once(" | ").expect("expected ' | '");
+        let signals: Vec<_> = signals.split_whitespace().collect();
+        let outputs: Vec<_> = outputs.split_whitespace().collect();
+
+        // `wiring[i]` is the segment that wire `'a' + i` is connected to
+        let digit = |wiring: &[usize], pattern: &str| {
+            let lit = pattern
+                .bytes()
+                .fold(0, |lit, b| lit | segments[wiring[(b - b'a') as usize]]);
+            digits.iter().position(|&d| d == lit)
+        };
+        let wiring = (0..7).permutations(7).find(|wiring| {
+            let shown: Option<Vec<_>> = signals.iter().map(|s| digit(wiring, s)).collect();
+            shown.is_some_and(|shown| shown.into_iter().sorted().eq(0..10))
+        })?;
+        total += outputs
+            .iter()
+            .try_fold(0, |value, o| Some(value * 10 + digit(&wiring, o)? as u32))?;
+    }
+    Some(total)
+}
+
+/// The highest point reached by any probe that hits the target, and the number of launch
+/// velocities that hit it (the two parts of day 17), found by launching every probe that could.
+///
+/// The target must lie below the launcher, otherwise arbitrarily high launches could hit it.
+pub fn day_17(input: &str) -> (i64, u32) {
+    let [x_lo, x_hi, y_lo, y_hi]: [i32; 4] = numbers(input).try_into().expect("expected a target");
+    assert!(y_hi < 0, "expected the target to be below the launcher");
+
+    let (mut highest, mut count) = (0, 0);
+    // anything faster overshoots the target on its first step, or on its first step below 0
+    for x_v in x_lo.min(0)..=x_hi.max(0) {
+        for y_v in y_lo..=-y_lo {
+            let (mut x, mut y, mut vel) = (0, 0, (x_v, y_v));
+            let mut peak = 0;
+            while y >= y_lo {
+                if (x_lo..=x_hi).contains(&x) && (y_lo..=y_hi).contains(&y) {
+                    highest = highest.max(i64::from(peak));
+                    count += 1;
+                    break;
+                }
+                x += vel.0;
+                y += vel.1;
+                peak = peak.max(y);
+                vel = (vel.0 - vel.0.signum(), vel.1 - 1);
+            }
+        }
+    }
+    (highest, count)
+}
+
+/// The number of wins for the player to move and the other player from a state of a game of
+/// Dirac Dice, counting every universe the rolls split into.
+fn dirac_wins(
+    [(pos, score), other]: [(usize, usize); 2],
+    wins: &mut FxHashMap<[(usize, usize); 2], [usize; 2]>,
+) -> [usize; 2] {
+    if let Some(&result) = wins.get(&[(pos, score), other]) {
+        return result;
+    }
+    let mut result = [0, 0];
+    for roll_1 in 1..=3 {
+        for roll_2 in 1..=3 {
+            for roll_3 in 1..=3 {
+                let pos = (pos + roll_1 + roll_2 + roll_3 - 1) % 10 + 1;
+                if score + pos >= 21 {
+                    result[0] += 1;
+                } else {
+                    let [other_wins, our_wins] = dirac_wins([other, (pos, score + pos)], wins);
+                    result[0] += our_wins;
+                    result[1] += other_wins;
+                }
+            }
+        }
+    }
+    wins.insert([(pos, score), other], result);
+    result
+}
+
+/// The answers to both parts of day 21, found by playing the game out turn by turn.
+pub fn day_21(input: &str) -> (usize, usize) {
+    let positions: Vec<usize> = input
+        .lines()
+        .filter_map(|line| Some(line.split_once(": ")?.1.trim().parse().unwrap()))
+        .collect();
+    let [p1, p2]: [usize; 2] = positions.try_into().expect("expected two players");
+
+    let mut players = [(p1, 0), (p2, 0)];
+    let mut rolls = 0;
+    let part_1 = loop {
+        let [(pos, score), other] = players;
+        let moves: usize = (rolls..rolls + 3).map(|roll| roll % 100 + 1).sum();
+        rolls += 3;
+        let pos = (pos + moves - 1) % 10 + 1;
+        if score + pos >= 1000 {
+            break other.1 * rolls;
+        }
+        players = [other, (pos, score + pos)];
+    };
+
+    let wins = dirac_wins([(p1, 0), (p2, 0)], &mut FxHashMap::default());
+    (part_1, wins[0].max(wins[1]))
+}
+
+enum Operand {
+    Register(usize),
+    Number(i64),
+}
+
+enum Op {
+    Add,
+    Mul,
+    Div,
+    Mod,
+    Eql,
+}
+
+struct Instruction {
+    op: Op,
+    register: usize,
+    operand: Operand,
+}
+
+fn register(name: &str) -> usize {
+    match name {
+        "w" => 0,
+        "x" => 1,
+        "y" => 2,
+        "z" => 3,
+        _ => panic!("unknown register {:?}", name),
+    }
+}
+
+/// Runs the instructions after an `inp w` from the given registers, with `w` as the input.
+fn run_block(block: &[Instruction], mut registers: [i64; 4]) -> [i64; 4] {
+    for instruction in block {
+        let b = match instruction.operand {
+            Operand::Register(r) => registers[r],
+            Operand::Number(n) => n,
+        };
+        let a = &mut registers[instruction.register];
+        *a = match instruction.op {
+            Op::Add => *a + b,
+            Op::Mul => *a * b,
+            Op::Div => *a / b,
+            Op::Mod => *a % b,
+            Op::Eql => (*a == b) as i64,
+        };
+    }
+    registers
+}
+
+struct Monad {
+    blocks: Vec<Vec<Instruction>>,
+    /// The number of `div z 26` instructions in each block and the blocks after it.
+    divs_left: Vec<u32>,
+    dead_ends: FxHashSet<(usize, [i64; 4])>,
+}
+
+impl Monad {
+    /// Finds the first accepted model number when trying the digits of each block in order.
+    fn search(&mut self, i: usize, registers: [i64; 4], digits: &[i64; 9]) -> Option<Vec<i64>> {
+        if i == self.blocks.len() {
+            return (registers[3] == 0).then(Vec::new);
+        }
+        // in these programs z only shrinks by a `div z 26`, as every other change to it multiplies
+        // it by 1 or 26 or adds something non-negative, so there's no way back to 0 from here
+        let z_bound = 26_i64.checked_pow(self.divs_left[i]).unwrap_or(i64::MAX);
+        if registers[3] >= z_bound || self.dead_ends.contains(&(i, registers)) {
+            return None;
+        }
+        for &w in digits {
+            let next = run_block(
+                &self.blocks[i],
+                [w, registers[1], registers[2], registers[3]],
+            );
+            if let Some(mut rest) = self.search(i + 1, next, digits) {
+                rest.insert(0, w);
+                return Some(rest);
+            }
+        }
+        self.dead_ends.insert((i, registers));
+        None
+    }
+}
+
+/// The largest and smallest model numbers accepted by the program (the two parts of day 24),
+/// found by running it on model numbers digit by digit and remembering which states can't lead
+/// to an accepted number, or `None` if it accepts none.
+pub fn day_24(input: &str) -> Option<(i64, i64)> {
+    let mut blocks: Vec<Vec<_>> = vec![];
+    for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        if line == "inp w" {
+            blocks.push(vec![]);
+            continue;
+        }
+        let [op, register_name, operand]: [&str; 3] = line
+            .split(' ')
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("expected an instruction with two operands");
+        let op = match op {
+            "add" => Op::Add,
+            "mul" => Op::Mul,
+            "div" => Op::Div,
+            "mod" => Op::Mod,
+            "eql" => Op::Eql,
+            _ => panic!("unknown instruction {:?}", op),
+        };
+        let operand = match operand.parse() {
+            Ok(n) => Operand::Number(n),
+            Err(_) => Operand::Register(register(operand)),
+        };
+        let instruction = Instruction {
+            op,
+            register: register(register_name),
+            operand,
+        };
+        blocks
+            .last_mut()
+            .expect("expected 'inp w' first")
+            .push(instruction);
+    }
+
+    let mut divs_left: Vec<u32> = blocks
+        .iter()
+        .map(|block| {
+            let is_div = |i: &Instruction| matches!(i.op, Op::Div) && i.register == 3;
+            block
+                .iter()
+                .filter(|&i| is_div(i) && matches!(i.operand, Operand::Number(26)))
+                .count() as u32
+        })
+        .collect();
+    for i in (0..divs_left.len().saturating_sub(1)).rev() {
+        divs_left[i] += divs_left[i + 1];
+    }
+
+    let mut monad = Monad {
+        blocks,
+        divs_left,
+        dead_ends: FxHashSet::default(),
+    };
+    let to_number = |digits: Vec<i64>| digits.iter().fold(0, |n, d| n * 10 + d);
+    let max = monad.search(0, [0; 4], &[9, 8, 7, 6, 5, 4, 3, 2, 1])?;
+    let min = monad.search(0, [0; 4], &[1, 2, 3, 4, 5, 6, 7, 8, 9])?;
+    Some((to_number(max), to_number(min)))
+}
+
+#[cfg(all(test, feature = "all"))]
+mod tests {
+    use std::fmt::Debug;
+
+    use super::*;
+    use crate::{
+        day_17, day_21, day_24, day_8,
+        generate::{generate, Rng},
+    };
+
+    /// Checks a real solution against its reference on an input made from each seed.
+    fn compare<T: PartialEq + Debug>(
+        seeds: u64,
+        input: impl Fn(&mut Rng, u64) -> String,
+        real: impl Fn(&str) -> T,
+        reference: impl Fn(&str) -> T,
+    ) {
+        for seed in 0..seeds {
+            let input = input(&mut Rng::new(seed), seed);
+            let (real, reference) = (real(&input), reference(&input));
+            assert!(
+                real == reference,
+                "seed {}: got {:?} but the reference got {:?} for\n{}",
+                seed,
+                real,
+                reference,
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test() {
+        // a generated display, with one pattern sometimes replaced by random segments
+        let display = |rng: &mut Rng, seed| {
+            let line = generate(8, seed, 1)
+                .unwrap()
+                .lines()
+                .next()
+                .unwrap()
+                .to_owned();
+            let mut words: Vec<_> = line.split(' ').map(str::to_owned).collect();
+            if rng.chance(0.3) {
+                let mut segments = *b"abcdefg";
+                rng.shuffle(&mut segments);
+                let i = *rng.choose(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 11, 12, 13, 14]);
+                words[i] = String::from_utf8(segments[..1 + rng.below(7)].to_vec()).unwrap();
+            }
+            words.join(" ")
+        };
+        let real = |input: &str| day_8::parse(input).ok().map(|p| day_8::part_2(&p));
+        compare(2000, display, real, day_8);
+
+        let target = |rng: &mut Rng, _| {
+            let (x_lo, y_hi) = (rng.between(-20, 20), rng.between(-15, -1));
+            let (x_hi, y_lo) = (x_lo + rng.between(0, 8), y_hi - rng.between(0, 8));
+            format!("target area: x={}..{}, y={}..{}", x_lo, x_hi, y_lo, y_hi)
+        };
+        let real = |input: &str| {
+            let target = day_17::parse(input).unwrap();
+            (day_17::part_1(&target), day_17::part_2(&target))
+        };
+        compare(5000, target, real, day_17);
+
+        let players = |_: &mut Rng, seed| {
+            let (p1, p2) = (seed / 10 + 1, seed % 10 + 1);
+            format!(
+                "Player 1 starting position: {}\nPlayer 2 starting position: {}",
+                p1, p2
+            )
+        };
+        let real = |input: &str| {
+            let players = day_21::parse(input).unwrap();
+            (day_21::part_1(&players), day_21::part_2(&players))
+        };
+        compare(100, players, real, day_21);
+
+        let real = |input: &str| {
+            let blocks = day_24::parse(input).unwrap();
+            Some((day_24::part_1(&blocks), day_24::part_2(&blocks)))
+        };
+        // searching for model numbers is slow even with the pruning, so check fewer of them
+        compare(25, |_, seed| generate(24, seed, 1).unwrap(), real, day_24);
+    }
+}
@@ -0,0 +1,97 @@
+//! Checks every day's answers for the real inputs against the snapshots in `tests/snapshots`.
+//!
+//! The inputs come from the `input` submodule, and the days whose input isn't checked out, or
+//! that have no snapshot yet, are skipped and listed. Run with `UPDATE_SNAPSHOTS=1` to write the
+//! current answers as the new snapshots.
+
+use std::{env, fs, path::PathBuf};
+
+use advent_of_code_2021::{Part, SOLUTIONS};
+
+/// The answers to both parts, one `part N: answer` line each, with multi-line answers starting
+/// on the line after.
+fn render(answers: &[(Part, String)]) -> String {
+    let mut out = String::new();
+    for (part, answer) in answers {
+        if answer.contains('\n') {
+            out += &format!("part {}:\n{}\n", part, answer.trim_end());
+        } else {
+            out += &format!("part {}: {}\n", part, answer);
+        }
+    }
+    out
+}
+
+/// Shows the lines that changed, prefixed with `-` for the snapshot's and `+` for the new ones.
+fn diff(expected: &str, got: &str) -> String {
+    let (expected, got): (Vec<_>, Vec<_>) = (expected.lines().collect(), got.lines().collect());
+    let mut out = String::new();
+    for i in 0..expected.len().max(got.len()) {
+        match (expected.get(i), got.get(i)) {
+            (Some(a), Some(b)) if a == b => out += &format!("  {}\n", a),
+            (a, b) => {
+                if let Some(a) = a {
+                    out += &format!("- {}\n", a);
+                }
+                if let Some(b) = b {
+                    out += &format!("+ {}\n", b);
+                }
+            }
+        }
+    }
+    out
+}
+
+#[test]
+fn test() {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let inputs = root.join("../input/2021");
+    let snapshots = root.join("tests/snapshots");
+    let update = env::var_os("UPDATE_SNAPSHOTS").is_some_and(|value| value != "0");
+
+    let mut failures = vec![];
+    let (mut no_input, mut no_snapshot) = (vec![], vec![]);
+    for solution in SOLUTIONS {
+        let day = solution.day();
+        let Ok(input) = fs::read_to_string(inputs.join(format!("day{}.txt", day))) else {
+            no_input.push(day.to_string());
+            continue;
+        };
+        let input = match solution.parse_input(&input) {
+            Ok(input) => input,
+            Err(e) => {
+                // which already says which day it is
+                failures.push(e.to_string());
+                continue;
+            }
+        };
+        let answers: Vec<_> = Part::ALL
+            .into_iter()
+            .map(|part| (part, solution.solve(part, &input).to_string()))
+            .collect();
+        let got = render(&answers);
+
+        let path = snapshots.join(format!("day{}.txt", day));
+        if update {
+            fs::create_dir_all(&snapshots).unwrap();
+            fs::write(&path, &got).unwrap();
+            continue;
+        }
+        match fs::read_to_string(&path) {
+            Ok(expected) if expected == got => {}
+            Ok(expected) => failures.push(format!("day {}:\n{}", day, diff(&expected, &got))),
+            Err(_) => no_snapshot.push(day.to_string()),
+        }
+    }
+
+    if !no_input.is_empty() {
+        eprintln!("skipped the days without an input: {}", no_input.join(", "));
+    }
+    if !no_snapshot.is_empty() {
+        eprintln!(
+            "skipped the days without a snapshot, run with UPDATE_SNAPSHOTS=1 to write them: {}",
+            no_snapshot.join(", ")
+        );
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
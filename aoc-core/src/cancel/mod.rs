@@ -0,0 +1,89 @@
+//! Cooperative cancellation, so that a runner can stop a search that's taking too long.
+//!
+//! The runner hands each part a [`Token`] through [`with_token`], and the long-running loops call
+//! [`check`] now and then. Once the token is cancelled, `check` unwinds with a [`Cancelled`]
+//! payload, which the runner catches and reports as a timeout. Outside the runner there is no
+//! token, and `check` does nothing.
+
+use std::{
+    cell::RefCell,
+    panic,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+/// A flag that asks the code running under it to stop, shared with whoever cancels it.
+#[derive(Debug, Clone, Default)]
+pub struct Token(Arc<AtomicBool>);
+
+impl Token {
+    pub fn new() -> Token {
+        Token::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// The payload [`check`] unwinds with once its token has been cancelled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+thread_local! {
+    static CURRENT: RefCell<Option<Token>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` on this thread with `token` as the one [`check`] looks at.
+pub fn with_token<T>(token: &Token, f: impl FnOnce() -> T) -> T {
+    // put the previous token back even if `f` unwinds
+    struct Restore(Option<Token>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CURRENT.with(|current| *current.borrow_mut() = self.0.take());
+        }
+    }
+
+    let _restore = Restore(CURRENT.with(|current| current.replace(Some(token.clone()))));
+    f()
+}
+
+/// Unwinds with [`Cancelled`] if the current token has been cancelled.
+pub fn check() {
+    let cancelled =
+        CURRENT.with(|current| current.borrow().as_ref().is_some_and(Token::is_cancelled));
+    if cancelled {
+        panic::resume_unwind(Box::new(Cancelled));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        check();
+        let token = Token::new();
+        assert_eq!(3, with_token(&token, || 1 + 2));
+
+        token.cancel();
+        let payload = panic::catch_unwind(|| {
+            with_token(&token, || {
+                check();
+                unreachable!()
+            })
+        })
+        .unwrap_err();
+        assert_eq!(Some(&Cancelled), payload.downcast_ref::<Cancelled>());
+        // the token only applies inside `with_token`
+        check();
+    }
+}
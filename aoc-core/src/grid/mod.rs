@@ -0,0 +1,297 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    parse::{end_of_input, lines, Line},
+    ParseError,
+};
+
+/// A position in a [`Grid`], as `(x, y)` where `x` is the column and `y` the row.
+pub type Point = (usize, usize);
+
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const ADJACENT: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells in row order.
+    ///
+    /// Panics if there aren't exactly `width * height` cells.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(width * height, cells.len(), "grid is not {width}x{height}");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Point) -> T) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut f)
+            .collect();
+        Grid::new(width, height, cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The number of cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, (x, y): Point) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// The index of a point in [`Grid::cells`].
+    pub fn index_of(&self, (x, y): Point) -> usize {
+        y * self.width + x
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.contains(p).then(|| &self.cells[self.index_of(p)])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        if !self.contains(p) {
+            return None;
+        }
+        let i = self.index_of(p);
+        Some(&mut self.cells[i])
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// Every point in the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (width, height) = (self.width, self.height);
+        (0..height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} is out of bounds");
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Moves from `p` by `(dx, dy)`, or returns `None` if that leaves the grid.
+    pub fn offset(&self, (x, y): Point, (dx, dy): (isize, isize)) -> Option<Point> {
+        let p = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(p).then_some(p)
+    }
+
+    /// Moves from `p` by `(dx, dy)`, wrapping around the edges as if the grid were a torus.
+    pub fn wrapping_offset(&self, (x, y): Point, (dx, dy): (isize, isize)) -> Point {
+        let wrap = |n: usize, d: isize, len: usize| (n as isize + d).rem_euclid(len as isize);
+        (
+            wrap(x, dx, self.width) as usize,
+            wrap(y, dy, self.height) as usize,
+        )
+    }
+
+    /// The points above, left, right and below `p` that are in the grid.
+    pub fn neighbours_4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |d| self.offset(p, d))
+    }
+
+    /// The points surrounding `p`, including diagonally, that are in the grid.
+    pub fn neighbours_8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        ADJACENT.into_iter().filter_map(move |d| self.offset(p, d))
+    }
+
+    /// Like [`Grid::neighbours_4`], but wrapping around the edges.
+    ///
+    /// On grids narrower or shorter than 3 cells the same point can appear more than once.
+    pub fn wrapping_neighbours_4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .map(move |d| self.wrapping_offset(p, d))
+    }
+
+    /// Like [`Grid::neighbours_8`], but wrapping around the edges.
+    ///
+    /// On grids narrower or shorter than 3 cells the same point can appear more than once.
+    pub fn wrapping_neighbours_8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        ADJACENT
+            .into_iter()
+            .map(move |d| self.wrapping_offset(p, d))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        self.get(p)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", p))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", p))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses lines of characters into a grid, or returns `None` if there are no lines.
+///
+/// `cell` converts each character, returning `None` for characters that aren't `expected`.
+pub fn from_lines<'a, T>(
+    lines: impl IntoIterator<Item = Line<'a>>,
+    mut cell: impl FnMut(char) -> Option<T>,
+    expected: &str,
+) -> Result<Option<Grid<T>>, ParseError> {
+    let mut cells = vec![];
+    let mut width = None;
+    for line in lines {
+        let mut row_width = 0;
+        for (i, c) in line.text.char_indices() {
+            match cell(c) {
+                Some(value) => cells.push(value),
+                None => {
+                    let bad = &line.text[i..i + c.len_utf8()];
+                    return Err(line.error(bad, format!("expected {}", expected)));
+                }
+            }
+            row_width += 1;
+        }
+        let width = *width.get_or_insert(row_width);
+        if row_width != width || width == 0 {
+            return Err(line.error(line.text, format!("expected a row of {} cells", width)));
+        }
+    }
+
+    Ok(width.map(|width| Grid::new(width, cells.len() / width, cells)))
+}
+
+impl<T> Grid<T> {
+    /// Parses a map with one character per cell, such as `#..#`.
+    ///
+    /// `cell` converts each character, returning `None` for characters that aren't `expected`.
+    pub fn parse_chars(
+        day: u8,
+        input: &str,
+        cell: impl FnMut(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Grid<T>, ParseError> {
+        from_lines(lines(day, input), cell, expected)?
+            .ok_or_else(|| end_of_input(day, input, "expected a row of cells"))
+    }
+}
+
+impl Grid<u8> {
+    /// Parses a map of single digits, such as `0123`.
+    pub fn parse_digits(day: u8, input: &str) -> Result<Grid<u8>, ParseError> {
+        Grid::parse_chars(day, input, |c| c.to_digit(10).map(|d| d as u8), "a digit")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let mut grid = Grid::parse_digits(0, "123\n456").unwrap();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(6, grid[(2, 1)]);
+        assert_eq!(None, grid.get((1, 2)));
+        assert_eq!(None, grid.get_mut((usize::MAX, usize::MAX)));
+        assert_eq!("123\n456", grid.to_string());
+        assert_eq!(
+            vec![&[1, 2, 3][..], &[4, 5, 6]],
+            grid.rows().collect::<Vec<_>>()
+        );
+        assert_eq!(vec![2, 5], grid.column(1).copied().collect::<Vec<_>>());
+        assert_eq!(3, grid.columns().count());
+
+        assert_eq!(
+            vec![(1, 0), (0, 1), (2, 1)],
+            grid.neighbours_4((1, 1)).collect::<Vec<_>>()
+        );
+        assert_eq!(3, grid.neighbours_8((0, 0)).count());
+        assert_eq!((2, 1), grid.wrapping_offset((0, 0), (-1, -1)));
+        assert_eq!(8, grid.wrapping_neighbours_8((0, 0)).count());
+
+        let tall = Grid::from_fn(2, 3, |(x, y)| x + 10 * y);
+        assert_eq!(Some(&21), tall.get((1, 2)));
+        assert_eq!(None, tall.get((2, 1)));
+
+        let err = Grid::parse_digits(9, "12\n1x").unwrap_err();
+        assert_eq!((2, 2), (err.line, err.column));
+        assert_eq!(2, Grid::parse_digits(9, "12\n123").unwrap_err().line);
+        assert_eq!(1, Grid::parse_digits(9, "").unwrap_err().line);
+    }
+}
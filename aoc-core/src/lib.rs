@@ -0,0 +1,249 @@
+//! The infrastructure shared by every year's solutions: the [`Solution`] trait, input parsing,
+//! grids, tracing, and with the `runner` feature the command-line runner that years register
+//! themselves with through [`Year`].
+
+use std::{any::Any, fmt::Display};
+
+pub mod cancel;
+pub mod grid;
+pub mod num;
+pub mod params;
+pub mod parse;
+pub mod rng;
+#[cfg(feature = "runner")]
+pub mod runner;
+pub mod trace;
+
+use num::BigUint;
+pub use params::{Param, Params};
+pub use parse::ParseError;
+use trace::Observer;
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i128),
+    /// An integer too large for [`Answer::Int`], which smaller ones are never stored as.
+    Big(BigUint),
+    /// Free-form text, which may span multiple lines (e.g. the letters rendered by day 13).
+    Text(String),
+    /// The part has no answer to compute (e.g. part 2 of day 25).
+    None,
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Int(n as i128)
+            }
+        })*
+    };
+}
+
+answer_from_int!(i32, i64, u32, u64, usize);
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Self {
+        match i128::try_from(n) {
+            Ok(n) => Answer::Int(n),
+            Err(_) => Answer::Big(BigUint::from(n)),
+        }
+    }
+}
+
+impl From<BigUint> for Answer {
+    fn from(n: BigUint) -> Self {
+        match n.to_i128() {
+            Some(n) => Answer::Int(n),
+            None => Answer::Big(n),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Big(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::None => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// An example input from a puzzle's description, and the answers it gives for parts 1 and 2.
+///
+/// Some examples only come with an answer for one of the parts.
+pub type Example = (&'static str, Option<Answer>, Option<Answer>);
+
+/// A solution to one day's puzzle.
+///
+/// The raw input is parsed once and both parts are solved from the result,
+/// so any problems with the input are reported by [`Solution::parse`].
+/// Work that both parts need (like aligning the scanners on day 19) is cached
+/// in the parsed input by whichever part runs first.
+pub trait Solution {
+    const DAY: u8;
+    type Input: Send + Sync + 'static;
+    /// The numbers the puzzle can be tuned with, which [`Solution::solve_with`] takes values for.
+    const PARAMS: &'static [Param] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part_1(input: &Self::Input) -> Answer;
+    fn part_2(input: &Self::Input) -> Answer;
+
+    fn try_part_1(input: &str) -> Result<Answer, ParseError> {
+        Self::parse(input).map(|input| Self::part_1(&input))
+    }
+
+    fn try_part_2(input: &str) -> Result<Answer, ParseError> {
+        Self::parse(input).map(|input| Self::part_2(&input))
+    }
+
+    /// Solves a part with the given values for [`Solution::PARAMS`].
+    ///
+    /// The defaults give the same answers as [`Solution::part_1`] and [`Solution::part_2`].
+    fn solve_with(input: &Self::Input, part: Part, _params: &Params) -> Answer {
+        match part {
+            Part::One => Self::part_1(input),
+            Part::Two => Self::part_2(input),
+        }
+    }
+
+    /// Solves a part like [`Solution::solve_with`] while showing `observer` each step of the
+    /// simulation behind it.
+    ///
+    /// Only the days that simulate step by step support this, and the rest return `None`.
+    fn trace(
+        _input: &Self::Input,
+        _part: Part,
+        _params: &Params,
+        _observer: &mut dyn Observer,
+    ) -> Option<Answer> {
+        None
+    }
+
+    /// The examples from the puzzle's description, for trying out the solution without an input.
+    fn examples() -> Vec<Example> {
+        vec![]
+    }
+}
+
+pub type ParsedInput = Box<dyn Any + Send + Sync>;
+
+/// An object-safe view of a [`Solution`], so that every day of a [`Year`] can be stored together.
+pub trait DynSolution: Sync {
+    fn day(&self) -> u8;
+    fn parse_input(&self, input: &str) -> Result<ParsedInput, ParseError>;
+
+    fn params(&self) -> &'static [Param];
+
+    /// Solves a part using an input returned by [`DynSolution::parse_input`] of the same day.
+    fn solve(&self, part: Part, input: &ParsedInput) -> Answer {
+        self.solve_with(part, input, &Params::default())
+    }
+
+    /// Like [`DynSolution::solve`], with values for the day's [`DynSolution::params`].
+    fn solve_with(&self, part: Part, input: &ParsedInput, params: &Params) -> Answer;
+
+    /// Like [`DynSolution::solve_with`], but through [`Solution::trace`].
+    fn trace(
+        &self,
+        part: Part,
+        input: &ParsedInput,
+        params: &Params,
+        observer: &mut dyn Observer,
+    ) -> Option<Answer>;
+
+    fn examples(&self) -> Vec<Example>;
+
+    fn run(&self, part: Part, input: &str) -> Result<Answer, ParseError> {
+        Ok(self.solve(part, &self.parse_input(input)?))
+    }
+}
+
+impl<S: Solution + Sync> DynSolution for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parse_input(&self, input: &str) -> Result<ParsedInput, ParseError> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn params(&self) -> &'static [Param] {
+        S::PARAMS
+    }
+
+    fn solve_with(&self, part: Part, input: &ParsedInput, params: &Params) -> Answer {
+        S::solve_with(downcast::<S>(input), part, params)
+    }
+
+    fn trace(
+        &self,
+        part: Part,
+        input: &ParsedInput,
+        params: &Params,
+        observer: &mut dyn Observer,
+    ) -> Option<Answer> {
+        S::trace(downcast::<S>(input), part, params, observer)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        S::examples()
+    }
+}
+
+fn downcast<S: Solution>(input: &ParsedInput) -> &S::Input {
+    input
+        .downcast_ref::<S::Input>()
+        .unwrap_or_else(|| panic!("input was not parsed by day {}", S::DAY))
+}
+
+/// One year's puzzles, as registered with the runner.
+pub struct Year {
+    pub year: u16,
+    /// Every day's solution that was compiled in, in order.
+    pub solutions: &'static [&'static dyn DynSolution],
+    /// Generates an input for a day from a seed and a scale, like the year's real inputs.
+    pub generate: fn(day: u8, seed: u64, scale: usize) -> Option<String>,
+    /// The directory holding the year's `day_N` modules, which `watch` looks for changes in.
+    pub source_dir: &'static str,
+}
+
+impl Year {
+    /// Looks up the solution for a day, numbered from 1, if it was compiled in.
+    pub fn solution(&self, day: u8) -> Option<&'static dyn DynSolution> {
+        self.solutions.iter().copied().find(|s| s.day() == day)
+    }
+}
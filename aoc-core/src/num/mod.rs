@@ -0,0 +1,213 @@
+//! Unsigned integers of any width, for the puzzles whose answers grow exponentially.
+
+use std::{cmp::Ordering, fmt::Display};
+
+use crate::Answer;
+
+/// An unsigned integer type that a solution can count in.
+///
+/// The fixed-width types report overflow instead of wrapping, so a solution can start with the
+/// narrowest type and only move to a wider one when it has to.
+pub trait Unsigned: Clone + Ord + Display + Into<Answer> {
+    fn from_u64(n: u64) -> Self;
+
+    /// `self + other`, or `None` if that doesn't fit in the type.
+    fn try_add(&self, other: &Self) -> Option<Self>;
+
+    /// `self - other`, where `other` must be no larger than `self`.
+    fn minus(&self, other: &Self) -> Self;
+
+    /// `self / divisor`, rounded down.
+    fn div_small(&self, divisor: u32) -> Self;
+}
+
+macro_rules! unsigned {
+    ($($t:ty),*) => {
+        $(impl Unsigned for $t {
+            fn from_u64(n: u64) -> Self {
+                n as $t
+            }
+
+            fn try_add(&self, other: &Self) -> Option<Self> {
+                <$t>::checked_add(*self, *other)
+            }
+
+            fn minus(&self, other: &Self) -> Self {
+                self - other
+            }
+
+            fn div_small(&self, divisor: u32) -> Self {
+                self / divisor as $t
+            }
+        })*
+    };
+}
+
+unsigned!(u64, u128);
+
+/// An unsigned integer that grows as large as it needs to.
+///
+/// It only does what [`Unsigned`] needs, which is enough for adding up counts.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    /// The digits in base 2^32, least significant first, without any zeros at the end.
+    digits: Vec<u32>,
+}
+
+impl BigUint {
+    fn trim(mut self) -> BigUint {
+        while self.digits.last() == Some(&0) {
+            self.digits.pop();
+        }
+        self
+    }
+
+    /// The value as an `i128`, if it's small enough.
+    pub fn to_i128(&self) -> Option<i128> {
+        if self.digits.len() > 4 {
+            return None;
+        }
+        let n = self
+            .digits
+            .iter()
+            .rev()
+            .fold(0u128, |n, &digit| n << 32 | digit as u128);
+        i128::try_from(n).ok()
+    }
+}
+
+impl From<u128> for BigUint {
+    fn from(mut n: u128) -> BigUint {
+        let mut digits = vec![];
+        while n > 0 {
+            digits.push(n as u32);
+            n >>= 32;
+        }
+        BigUint { digits }
+    }
+}
+
+impl Unsigned for BigUint {
+    fn from_u64(n: u64) -> Self {
+        BigUint::from(n as u128)
+    }
+
+    fn try_add(&self, other: &Self) -> Option<Self> {
+        let len = self.digits.len().max(other.digits.len());
+        let mut digits = Vec::with_capacity(len + 1);
+        let mut carry = 0;
+        for i in 0..len {
+            let a = *self.digits.get(i).unwrap_or(&0) as u64;
+            let b = *other.digits.get(i).unwrap_or(&0) as u64;
+            let sum = a + b + carry;
+            digits.push(sum as u32);
+            carry = sum >> 32;
+        }
+        digits.push(carry as u32);
+        Some(BigUint { digits }.trim())
+    }
+
+    fn minus(&self, other: &Self) -> Self {
+        assert!(other <= self, "can't subtract {} from {}", other, self);
+        let mut digits = Vec::with_capacity(self.digits.len());
+        let mut borrow = 0;
+        for (i, &a) in self.digits.iter().enumerate() {
+            let b = *other.digits.get(i).unwrap_or(&0) as i64;
+            let mut difference = a as i64 - b - borrow;
+            borrow = (difference < 0) as i64;
+            difference += borrow << 32;
+            digits.push(difference as u32);
+        }
+        BigUint { digits }.trim()
+    }
+
+    fn div_small(&self, divisor: u32) -> Self {
+        let mut digits = vec![0; self.digits.len()];
+        let mut remainder = 0u64;
+        for (i, &digit) in self.digits.iter().enumerate().rev() {
+            let n = remainder << 32 | digit as u64;
+            digits[i] = (n / divisor as u64) as u32;
+            remainder = n % divisor as u64;
+        }
+        BigUint { digits }.trim()
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let by_len = self.digits.len().cmp(&other.digits.len());
+        by_len.then_with(|| self.digits.iter().rev().cmp(other.digits.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for BigUint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // split off 9 decimal digits at a time, which are written out most significant first
+        const CHUNK: u32 = 1_000_000_000;
+        let mut chunks = vec![];
+        let mut n = self.clone();
+        while !n.digits.is_empty() {
+            let rest = n.div_small(CHUNK);
+            chunks.push(n.minus(&mul_small(&rest, CHUNK)).to_i128().unwrap());
+            n = rest;
+        }
+        match chunks.split_last() {
+            None => write!(f, "0"),
+            Some((first, rest)) => {
+                write!(f, "{}", first)?;
+                rest.iter()
+                    .rev()
+                    .try_for_each(|chunk| write!(f, "{:09}", chunk))
+            }
+        }
+    }
+}
+
+fn mul_small(n: &BigUint, factor: u32) -> BigUint {
+    let mut digits = Vec::with_capacity(n.digits.len() + 1);
+    let mut carry = 0;
+    for &digit in &n.digits {
+        let product = digit as u64 * factor as u64 + carry;
+        digits.push(product as u32);
+        carry = product >> 32;
+    }
+    digits.push(carry as u32);
+    BigUint { digits }.trim()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        assert_eq!(None, u64::MAX.try_add(&1));
+        assert_eq!(Some(u64::MAX as u128 + 1), (u64::MAX as u128).try_add(&1));
+        assert_eq!(3, 7u64.div_small(2));
+
+        let big = |n: u128| BigUint::from(n);
+        assert_eq!("0", BigUint::default().to_string());
+        assert_eq!(u128::MAX.to_string(), big(u128::MAX).to_string());
+        assert_eq!("1000000000", big(1_000_000_000).to_string());
+
+        let sum = big(u128::MAX).try_add(&big(u128::MAX)).unwrap();
+        assert_eq!("680564733841876926926749214863536422910", sum.to_string());
+        assert_eq!(big(u128::MAX), sum.div_small(2));
+        assert_eq!(big(u128::MAX), sum.minus(&big(u128::MAX)));
+        assert_eq!(BigUint::default(), sum.minus(&sum));
+        assert!(big(u128::MAX) < sum && big(2) < big(3));
+        assert_eq!(None, sum.to_i128());
+        assert_eq!(Some(12), big(12).to_i128());
+
+        assert_eq!(Answer::Int(5), BigUint::from_u64(5).into());
+        assert_eq!(Answer::Big(sum.clone()), sum.clone().into());
+        assert_eq!(sum.to_string(), Answer::from(sum).to_string());
+        assert_eq!(Answer::Big(big(u128::MAX)), u128::MAX.into());
+    }
+}
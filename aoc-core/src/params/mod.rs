@@ -0,0 +1,108 @@
+//! Named numbers that tune a day's puzzle, like how many steps to simulate, so variations on it
+//! can be explored without editing the solution.
+
+use std::{collections::BTreeMap, fmt::Display, ops::RangeInclusive};
+
+/// A parameter a day declares in [`crate::Solution::PARAMS`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub help: &'static str,
+    /// The value the puzzle itself uses.
+    pub default: i64,
+    /// The values the solution can handle, e.g. without overflowing.
+    pub range: RangeInclusive<i64>,
+}
+
+impl Display for Param {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {} [default: {}, {}..={}]",
+            self.name,
+            self.help,
+            self.default,
+            self.range.start(),
+            self.range.end()
+        )
+    }
+}
+
+/// The values to solve a day with, where any parameters that weren't set keep their defaults.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params(BTreeMap<&'static str, i64>);
+
+impl Params {
+    pub fn get(&self, param: &Param) -> i64 {
+        self.0.get(param.name).copied().unwrap_or(param.default)
+    }
+
+    /// Like [`Params::get`], for parameters whose range fits in a `usize`.
+    pub fn get_usize(&self, param: &Param) -> usize {
+        self.get(param)
+            .try_into()
+            .unwrap_or_else(|_| panic!("{} should not be negative", param.name))
+    }
+
+    /// Sets one of a day's `params` from text like `name=value`, checking the value is in range.
+    pub fn set(&mut self, params: &[Param], setting: &str) -> Result<(), String> {
+        let (name, value) = setting
+            .split_once('=')
+            .ok_or_else(|| format!("expected a parameter like name=value, got '{}'", setting))?;
+        let param = params.iter().find(|p| p.name == name).ok_or_else(|| {
+            let names: Vec<_> = params.iter().map(|p| p.name).collect();
+            match names.is_empty() {
+                true => format!("unknown parameter '{}', this day has none", name),
+                false => format!(
+                    "unknown parameter '{}', expected one of: {}",
+                    name,
+                    names.join(", ")
+                ),
+            }
+        })?;
+        match value.parse() {
+            Ok(value) if param.range.contains(&value) => {
+                self.0.insert(param.name, value);
+                Ok(())
+            }
+            _ => Err(format!(
+                "invalid value '{}' for '{}', expected {}..={}",
+                value,
+                name,
+                param.range.start(),
+                param.range.end()
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let params = [Param {
+            name: "steps",
+            help: "how many steps to take",
+            default: 10,
+            range: 0..=20,
+        }];
+        let mut values = Params::default();
+        assert_eq!(10, values.get(&params[0]));
+        values.set(&params, "steps=20").unwrap();
+        assert_eq!(20, values.get_usize(&params[0]));
+
+        assert!(values.set(&params, "steps=21").is_err());
+        assert!(values.set(&params, "steps").is_err());
+        assert_eq!(
+            Err("unknown parameter 'jumps', expected one of: steps".to_string()),
+            values.set(&params, "jumps=1")
+        );
+        assert_eq!(
+            Err("unknown parameter 'jumps', this day has none".to_string()),
+            values.set(&[], "jumps=1")
+        );
+        assert_eq!(20, values.get(&params[0]));
+    }
+}
@@ -0,0 +1,254 @@
+//! Reading puzzle inputs line by line, with errors that point at the offending text.
+//!
+//! The combinators on [`Line`] take slices of the line's own text and hand back slices of it, so
+//! an error from any of them can say exactly where in the line it went wrong.
+
+use std::{any::type_name, error::Error, fmt::Display, ops::RangeInclusive, str::FromStr};
+
+/// An error in a puzzle input, pointing at the offending text.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParseError {
+    pub day: u8,
+    /// The 1-based line number.
+    pub line: usize,
+    /// The 1-based column, counted in characters.
+    pub column: usize,
+    /// The offending text, which is empty if the input ended too early.
+    pub text: String,
+    /// What was expected instead.
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(
+        day: u8,
+        line: usize,
+        column: usize,
+        text: impl Into<String>,
+        message: impl Into<String>,
+    ) -> ParseError {
+        ParseError {
+            day,
+            line,
+            column,
+            text: text.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: {}",
+            self.day, self.line, self.column, self.message
+        )?;
+        if self.text.is_empty() {
+            write!(f, ", found nothing")
+        } else {
+            write!(f, ", found {:?}", self.text)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// A line of a puzzle input, which knows where it came from so it can report errors.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub day: u8,
+    /// The 1-based line number.
+    pub number: usize,
+    pub text: &'a str,
+}
+
+/// Splits a day's input into its [`Line`]s.
+pub fn lines(day: u8, input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(move |(i, text)| Line {
+        day,
+        number: i + 1,
+        text,
+    })
+}
+
+/// The first line of an input, for the puzzles that fit on one, or an error with `message` if
+/// the input is empty.
+pub fn first_line<'a>(
+    day: u8,
+    input: &'a str,
+    message: impl Into<String>,
+) -> Result<Line<'a>, ParseError> {
+    lines(day, input)
+        .next()
+        .ok_or_else(|| end_of_input(day, input, message))
+}
+
+/// Splits lines into the sections between blank lines, leaving out any empty sections.
+pub fn sections<'l, 'a>(lines: &'l [Line<'a>]) -> impl Iterator<Item = &'l [Line<'a>]> {
+    lines
+        .split(|line| line.text.trim().is_empty())
+        .filter(|section| !section.is_empty())
+}
+
+impl<'a> Line<'a> {
+    /// Reports an error at `at`, which should be a slice of this line's text.
+    pub fn error(&self, at: &str, message: impl Into<String>) -> ParseError {
+        let start = self.text.as_ptr() as usize;
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&offset| offset <= self.text.len())
+            .unwrap_or(0);
+        let column = self.text[..offset].chars().count() + 1;
+        ParseError::new(self.day, self.number, column, at, message)
+    }
+
+    /// Reports an error at the end of this line, for when something is missing.
+    pub fn error_at_end(&self, message: impl Into<String>) -> ParseError {
+        self.error(&self.text[self.text.len()..], message)
+    }
+
+    pub fn parse<T: FromStr>(&self, field: &'a str) -> Result<T, ParseError> {
+        field
+            .parse()
+            .map_err(|_| self.error(field, format!("expected a valid {}", type_name::<T>())))
+    }
+
+    pub fn split_once(
+        &self,
+        s: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        s.split_once(delimiter)
+            .ok_or_else(|| self.error(s, format!("expected {:?}", delimiter)))
+    }
+
+    pub fn strip_prefix(&self, s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        s.strip_prefix(prefix)
+            .ok_or_else(|| self.error(s, format!("expected {:?}", prefix)))
+    }
+
+    /// Splits a separated list with exactly `N` fields.
+    pub fn split_n<const N: usize>(
+        &self,
+        s: &'a str,
+        separator: &str,
+    ) -> Result<[&'a str; N], ParseError> {
+        let mut fields = s.split(separator);
+        let mut out = [""; N];
+        for field in &mut out {
+            *field = fields
+                .next()
+                .ok_or_else(|| self.error(s, format!("expected {} values", N)))?;
+        }
+        if let Some(extra) = fields.next() {
+            return Err(self.error(extra, format!("expected only {} values", N)));
+        }
+        Ok(out)
+    }
+
+    /// Parses a separated list with exactly `N` fields.
+    pub fn parse_n<T: FromStr, const N: usize>(
+        &self,
+        s: &'a str,
+        separator: &str,
+    ) -> Result<[T; N], ParseError> {
+        let fields = self.split_n::<N>(s, separator)?;
+        let mut out = Vec::with_capacity(N);
+        for field in fields {
+            out.push(self.parse(field)?);
+        }
+        Ok(out.try_into().unwrap_or_else(|_| unreachable!()))
+    }
+
+    /// Parses two separated values, like the `x,y` of a point.
+    pub fn pair<T: FromStr>(&self, s: &'a str, separator: &str) -> Result<(T, T), ParseError> {
+        let [a, b] = self.parse_n(s, separator)?;
+        Ok((a, b))
+    }
+
+    /// Parses a separated list of any length, using `field` to parse each field.
+    pub fn list_with<T>(
+        &self,
+        s: &'a str,
+        separator: &str,
+        field: impl FnMut(&'a str) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        s.split(separator).map(field).collect()
+    }
+
+    /// Parses a separated list of any length, like `3,4,3,1,2`.
+    pub fn list<T: FromStr>(&self, s: &'a str, separator: &str) -> Result<Vec<T>, ParseError> {
+        self.list_with(s, separator, |field| self.parse(field))
+    }
+
+    /// Parses a range like `x=10..12`, where `key` is the part before the `=` and both ends are
+    /// included.
+    pub fn range<T: FromStr + PartialOrd>(
+        &self,
+        s: &'a str,
+        key: &str,
+    ) -> Result<RangeInclusive<T>, ParseError> {
+        let range = self.strip_prefix(s, &format!("{}=", key))?;
+        let (start, end) = self.pair(range, "..")?;
+        if start > end {
+            return Err(self.error(range, "expected the start of the range to come first"));
+        }
+        Ok(start..=end)
+    }
+}
+
+/// Reports that the input ended early, just after its last line.
+pub fn end_of_input(day: u8, input: &str, message: impl Into<String>) -> ParseError {
+    ParseError::new(day, input.lines().count() + 1, 1, "", message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let line = lines(2, "a\nforward x").nth(1).unwrap();
+        let (_, num) = line.split_once(line.text, " ").unwrap();
+        let err = line.parse::<i64>(num).unwrap_err();
+        assert_eq!(ParseError::new(2, 2, 9, "x", "expected a valid i64"), err);
+        assert_eq!(
+            "day 2, line 2, column 9: expected a valid i64, found \"x\"",
+            err.to_string()
+        );
+
+        let line = lines(0, "1,2,3,4").next().unwrap();
+        assert_eq!(Ok([1, 2, 3, 4]), line.parse_n::<u8, 4>(line.text, ","));
+        assert_eq!(7, line.parse_n::<u8, 3>(line.text, ",").unwrap_err().column);
+        assert_eq!(8, line.error_at_end("expected more").column);
+        assert_eq!(Ok(vec![1, 2, 3, 4]), line.list::<u8>(line.text, ","));
+        assert_eq!(Ok(["1", "2", "3", "4"]), line.split_n(line.text, ","));
+
+        let line = lines(17, "target area: x=20..30, y=-5..-10")
+            .next()
+            .unwrap();
+        let (x, y) = line.split_once(line.text, ", ").unwrap();
+        let x = line.strip_prefix(x, "target area: ").unwrap();
+        assert_eq!(Ok(20..=30), line.range(x, "x"));
+        let err = line.range::<i32>(y, "y").unwrap_err();
+        assert_eq!(
+            (26, "expected the start of the range to come first"),
+            (err.column, err.message.as_str())
+        );
+        assert_eq!(24, line.range::<i32>(y, "x").unwrap_err().column);
+        assert_eq!(Ok((-5, -10)), line.pair(&y[2..], ".."));
+
+        let input = "a\n\nb\n c\n \n\n";
+        let lines: Vec<_> = lines(4, input).collect();
+        let sections: Vec<Vec<_>> = sections(&lines)
+            .map(|section| section.iter().map(|line| line.number).collect())
+            .collect();
+        assert_eq!(vec![vec![1], vec![3, 4]], sections);
+        assert_eq!(
+            Ok("a"),
+            first_line(4, input, "expected a").map(|line| line.text)
+        );
+        assert_eq!(1, first_line(4, "", "expected a").unwrap_err().line);
+    }
+}
@@ -0,0 +1,73 @@
+//! Seeded randomness for generating inputs, so that the same seed always gives the same input on
+//! every platform and with every version of the dependencies.
+
+/// A small pseudo-random number generator (SplitMix64), so that a seed always gives the same input.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, which must not be empty.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "can't pick a number below 0");
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    /// A number in `lo..=hi`, which must not be empty.
+    pub fn between(&mut self, lo: i64, hi: i64) -> i64 {
+        assert!(lo <= hi, "can't pick a number between {lo} and {hi}");
+        let n = (hi as i128 - lo as i128) as u128 + 1;
+        (lo as i128 + ((self.next_u64() as u128 * n) >> 64) as i128) as i64
+    }
+
+    /// Returns true with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let mut rng = Rng::new(7);
+        let first: Vec<_> = (0..5).map(|_| rng.next_u64()).collect();
+        let mut again = Rng::new(7);
+        assert!(first.iter().all(|&n| n == again.next_u64()));
+        assert_ne!(first[0], Rng::new(8).next_u64());
+
+        let mut items = [1, 2, 3, 4, 5];
+        rng.shuffle(&mut items);
+        items.sort_unstable();
+        assert_eq!([1, 2, 3, 4, 5], items);
+        assert!((0..1000).all(|_| (-3..=3).contains(&rng.between(-3, 3))));
+        assert!((0..1000).all(|_| rng.below(10) < 10));
+        assert_eq!(4, rng.between(4, 4));
+        rng.between(i64::MIN, i64::MAX);
+        assert!((0..100).all(|_| rng.chance(1.0) && !rng.chance(0.0)));
+        assert_eq!(&'a', rng.choose(&['a']));
+    }
+}
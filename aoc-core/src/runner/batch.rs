@@ -0,0 +1,242 @@
+//! Solving one day for every input in a directory, for `run --inputs-dir`.
+
+use std::{
+    fs,
+    path::Path,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use crate::{Answer, DynSolution, Params, Part};
+
+use crate::runner::{
+    pool::{Job, Pool},
+    watchdog::{quiet_panics, Failure, Watchdog},
+};
+
+/// The outcome of solving one input file.
+pub struct FileRun {
+    name: String,
+    parse_time: Duration,
+    /// Each part's answer, or why it has none.
+    parts: Vec<(Part, Result<Answer, Failure>, Duration)>,
+    /// Why the input couldn't be solved at all.
+    error: Option<String>,
+}
+
+impl FileRun {
+    fn failed(&self) -> bool {
+        self.error.is_some() || self.parts.iter().any(|(_, answer, _)| answer.is_err())
+    }
+
+    fn status(&self) -> String {
+        if let Some(e) = &self.error {
+            return format!("ERROR  {}", e);
+        }
+        let failures = self.parts.iter().filter_map(|(part, answer, _)| {
+            Some(match answer.as_ref().err()? {
+                Failure::Panicked(message) => format!("PANIC  part {}: {}", part, message),
+                Failure::TimedOut(timeout) => {
+                    format!("TIMEOUT  part {} after {:?}", part, timeout)
+                }
+            })
+        });
+        failures
+            .reduce(|a, b| a + "; " + &b)
+            .unwrap_or_else(|| "ok".to_string())
+    }
+}
+
+/// Parses and solves one file under `watchdog`, so that one bad input can't stop the batch.
+fn solve_file(
+    solution: &'static dyn DynSolution,
+    parts: &[Part],
+    params: &Params,
+    watchdog: &Watchdog,
+    path: &Path,
+) -> FileRun {
+    let mut run = FileRun {
+        name: path.file_name().unwrap().to_string_lossy().into_owned(),
+        parse_time: Duration::ZERO,
+        parts: vec![],
+        error: None,
+    };
+    let input = match fs::read_to_string(path) {
+        Ok(input) => input,
+        Err(e) => {
+            run.error = Some(format!("could not read {}: {}", path.display(), e));
+            return run;
+        }
+    };
+
+    let instant = Instant::now();
+    let input = watchdog.run(move || solution.parse_input(&input));
+    run.parse_time = instant.elapsed();
+    let input = match input {
+        Ok(Ok(input)) => Arc::new(input),
+        Ok(Err(e)) => {
+            run.error = Some(e.to_string());
+            return run;
+        }
+        Err(failure) => {
+            run.error = Some(format!("parsing {}", failure));
+            return run;
+        }
+    };
+
+    for &part in parts {
+        let instant = Instant::now();
+        let (input, params) = (input.clone(), params.clone());
+        let answer = watchdog.run(move || solution.solve_with(part, &input, &params));
+        run.parts.push((part, answer, instant.elapsed()));
+    }
+    run
+}
+
+/// Solves every file in `dir` on `jobs` threads, returning the runs in order of file name.
+pub fn solve_dir(
+    solution: &'static dyn DynSolution,
+    parts: &[Part],
+    params: &Params,
+    watchdog: &Watchdog,
+    dir: &Path,
+    jobs: usize,
+) -> Result<Vec<FileRun>, String> {
+    let read_error = |e| format!("could not read {}: {}", dir.display(), e);
+    let mut paths = vec![];
+    for entry in fs::read_dir(dir).map_err(read_error)? {
+        let path = entry.map_err(read_error)?.path();
+        if path.is_file() {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    let runs: Vec<_> = paths.iter().map(|_| Mutex::new(None)).collect();
+    quiet_panics(|| {
+        Pool::run(
+            jobs,
+            paths.iter().zip(&runs).map(|(path, run)| {
+                let job: Job = Box::new(move |_| {
+                    let file_run = solve_file(solution, parts, params, watchdog, path);
+                    *run.lock().unwrap() = Some(file_run);
+                });
+                job
+            }),
+        )
+    });
+
+    Ok(runs
+        .into_iter()
+        .map(|run| run.into_inner().unwrap().unwrap())
+        .collect())
+}
+
+fn format_answer(answer: &Result<Answer, Failure>, time: Duration) -> String {
+    match answer {
+        // multi-line answers would break up the table
+        Ok(answer) => format!("{} ({:.2?})", answer.to_string().escape_debug(), time),
+        Err(Failure::Panicked(_)) => "panicked".to_string(),
+        Err(Failure::TimedOut(_)) => "timed out".to_string(),
+    }
+}
+
+/// Lays out the runs as a table of answers and timings, one file per row.
+fn table(parts: &[Part], runs: &[FileRun]) -> String {
+    let mut rows = vec![];
+    let mut header = vec!["file".to_string(), "parse".to_string()];
+    header.extend(parts.iter().map(|part| format!("part {}", part)));
+    header.push("status".to_string());
+    rows.push(header);
+    for run in runs {
+        let mut row = vec![run.name.clone()];
+        if run.error.is_some() {
+            row.extend((0..=parts.len()).map(|_| "-".to_string()));
+        } else {
+            row.push(format!("{:.2?}", run.parse_time));
+            row.extend(
+                run.parts
+                    .iter()
+                    .map(|(_, answer, time)| format_answer(answer, *time)),
+            );
+        }
+        row.push(run.status());
+        rows.push(row);
+    }
+
+    let widths: Vec<usize> = (0..rows[0].len())
+        .map(|i| rows.iter().map(|row| row[i].chars().count()).max().unwrap())
+        .collect();
+    let lines = rows.iter().map(|row| {
+        let cells = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{:width$}", cell, width = width));
+        cells.collect::<Vec<_>>().join("  ").trim_end().to_string()
+    });
+    lines.collect::<Vec<_>>().join("\n")
+}
+
+/// Prints the table of runs and a summary, returning whether any failed.
+pub fn print_report(parts: &[Part], runs: &[FileRun]) -> bool {
+    println!("{}", table(parts, runs));
+    let failed = runs.iter().filter(|run| run.failed()).count();
+    println!("\n{} of {} inputs solved", runs.len() - failed, runs.len());
+    failed > 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{parse::lines, ParseError, Solution};
+
+    struct Panics;
+
+    impl Solution for Panics {
+        const DAY: u8 = 1;
+        type Input = u32;
+
+        fn parse(input: &str) -> Result<u32, ParseError> {
+            let depths: Vec<u32> = lines(1, input)
+                .map(|line| line.parse(line.text))
+                .collect::<Result<_, _>>()?;
+            Ok(depths.len() as u32)
+        }
+
+        fn part_1(input: &u32) -> Answer {
+            Answer::Int(*input as i128)
+        }
+
+        fn part_2(_: &u32) -> Answer {
+            panic!("out of cheese")
+        }
+    }
+
+    #[test]
+    fn test() {
+        let dir = std::env::temp_dir().join(format!("batch-test-{}", std::process::id()));
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        fs::write(dir.join("b.txt"), "1\n2\n3").unwrap();
+        fs::write(dir.join("a.txt"), "1\nx").unwrap();
+
+        let watchdog = Watchdog::new(None);
+        let runs = solve_dir(&Panics, &Part::ALL, &Params::default(), &watchdog, &dir, 2).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(2, runs.len());
+        assert_eq!("a.txt", runs[0].name);
+        assert!(runs[0]
+            .status()
+            .starts_with("ERROR  day 1, line 2, column 1"));
+        assert_eq!(Ok(Answer::Int(3)), runs[1].parts[0].1);
+        assert_eq!("PANIC  part 2: out of cheese", runs[1].status());
+        assert!(runs.iter().all(FileRun::failed));
+
+        let table = table(&Part::ALL, &runs);
+        let lines: Vec<_> = table.lines().collect();
+        assert_eq!(3, lines.len());
+        assert!(lines[0].starts_with("file   parse"));
+        assert!(lines[1].starts_with("a.txt  -"));
+        assert!(lines[2].contains("  panicked  PANIC"));
+    }
+}
@@ -0,0 +1,348 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
+
+use crate::{DynSolution, Params, Part};
+use serde::{Deserialize, Serialize};
+
+use crate::runner::memory::{self, Allocs};
+use crate::runner::watchdog::{Failure, Watchdog};
+
+pub struct BenchArgs {
+    pub warmup: u32,
+    pub iterations: u32,
+    pub save: Option<PathBuf>,
+    pub baseline: Option<PathBuf>,
+    /// How much slower a median may get, as a percentage, before it counts as a regression.
+    pub threshold: f64,
+}
+
+impl Default for BenchArgs {
+    fn default() -> Self {
+        BenchArgs {
+            warmup: 3,
+            iterations: 10,
+            save: None,
+            baseline: None,
+            threshold: 10.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub stddev_ns: u64,
+    /// What the stage allocated, which is the same every iteration, in reports written with the
+    /// `alloc-stats` feature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allocs: Option<Allocs>,
+}
+
+impl Stats {
+    fn new(samples: &mut [Duration]) -> Stats {
+        samples.sort();
+        let ns: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        let n = ns.len() as f64;
+        let mean = ns.iter().sum::<f64>() / n;
+        let variance = ns.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+        let mid = ns.len() / 2;
+        let median = if ns.len().is_multiple_of(2) {
+            (ns[mid - 1] + ns[mid]) / 2.0
+        } else {
+            ns[mid]
+        };
+
+        Stats {
+            min_ns: ns[0] as u64,
+            median_ns: median as u64,
+            mean_ns: mean as u64,
+            stddev_ns: variance.sqrt() as u64,
+            allocs: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DayResult {
+    pub day: u8,
+    pub parse: Stats,
+    pub part_1: Option<Stats>,
+    pub part_2: Option<Stats>,
+    /// The parts that panicked or ran out of time, which stop being benchmarked when they do.
+    #[serde(skip)]
+    pub failures: Vec<(Part, Failure)>,
+}
+
+impl DayResult {
+    fn stages(&self) -> [(&'static str, Option<Stats>); 3] {
+        [
+            ("parse", Some(self.parse)),
+            ("part 1", self.part_1),
+            ("part 2", self.part_2),
+        ]
+    }
+}
+
+/// The results of a whole `bench` run, as saved by `--save`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    pub warmup: u32,
+    pub iterations: u32,
+    pub days: Vec<DayResult>,
+}
+
+/// Parses and solves `input` repeatedly under `watchdog`, timing each stage separately.
+///
+/// A part that fails is left out of the rest of the run and reported in
+/// [`DayResult::failures`], while failing to parse fails the whole day.
+pub fn bench_day(
+    args: &BenchArgs,
+    watchdog: &Watchdog,
+    solution: &'static dyn DynSolution,
+    parts: &[Part],
+    params: &Params,
+    input: String,
+) -> Result<DayResult, String> {
+    let input: Arc<str> = input.into();
+    let mut parts = parts.to_vec();
+    let mut failures = vec![];
+    let mut parse = vec![];
+    let mut solve = [vec![], vec![]];
+    // every iteration allocates the same, so keeping the last one's counts is enough
+    let mut parse_allocs = None;
+    let mut solve_allocs = [None, None];
+    for i in 0..args.warmup + args.iterations {
+        let input = input.clone();
+        let (parsed, elapsed, allocs) =
+            super::measure(watchdog, move || solution.parse_input(&input));
+        let parsed = match parsed {
+            Ok(parsed) => Arc::new(parsed.map_err(|e| e.to_string())?),
+            Err(failure) => return Err(format!("parsing {}", failure)),
+        };
+        let measured = i >= args.warmup;
+        if measured {
+            parse.push(elapsed);
+            parse_allocs = allocs;
+        }
+        parts.retain(|&part| {
+            let index = part.number() as usize - 1;
+            let (parsed, params) = (parsed.clone(), params.clone());
+            let (solved, elapsed, allocs) = super::measure(watchdog, move || {
+                solution.solve_with(part, &parsed, &params)
+            });
+            if let Err(failure) = solved {
+                failures.push((part, failure));
+                solve[index].clear();
+                return false;
+            }
+            if measured {
+                solve[index].push(elapsed);
+                solve_allocs[index] = allocs;
+            }
+            true
+        });
+    }
+
+    let [part_1, part_2] = [0, 1].map(|i| {
+        if solve[i].is_empty() {
+            None
+        } else {
+            Some(Stats {
+                allocs: solve_allocs[i],
+                ..Stats::new(&mut solve[i])
+            })
+        }
+    });
+    Ok(DayResult {
+        day: solution.day(),
+        parse: Stats {
+            allocs: parse_allocs,
+            ..Stats::new(&mut parse)
+        },
+        part_1,
+        part_2,
+        failures,
+    })
+}
+
+fn ns(n: u64) -> String {
+    format!("{:.2?}", Duration::from_nanos(n))
+}
+
+/// The allocation columns, which are only there when allocations were counted.
+fn alloc_columns(allocs: Option<Allocs>) -> String {
+    match allocs {
+        Some(allocs) => format!(
+            "  {:>10}  {:>10}  {:>10}",
+            allocs.count,
+            memory::bytes(allocs.bytes),
+            memory::bytes(allocs.peak_bytes)
+        ),
+        None => String::new(),
+    }
+}
+
+pub fn print_report(report: &Report) {
+    let counted = report
+        .days
+        .iter()
+        .any(|result| result.parse.allocs.is_some());
+    let header = match counted {
+        true => format!("  {:>10}  {:>10}  {:>10}", "allocs", "allocated", "peak"),
+        false => String::new(),
+    };
+    println!(
+        "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}  {:>10}{}",
+        "day", "stage", "min", "median", "mean", "stddev", header
+    );
+    for result in &report.days {
+        for (stage, stats) in result.stages() {
+            if let Some(stats) = stats {
+                println!(
+                    "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}  {:>10}{}",
+                    result.day,
+                    stage,
+                    ns(stats.min_ns),
+                    ns(stats.median_ns),
+                    ns(stats.mean_ns),
+                    ns(stats.stddev_ns),
+                    alloc_columns(stats.allocs),
+                );
+            }
+        }
+        for (part, failure) in &result.failures {
+            println!(
+                "{:>3}  {:<6}  FAILED     {}",
+                result.day,
+                format!("part {}", part.number()),
+                failure
+            );
+        }
+    }
+}
+
+pub fn save_report(report: &Report, path: &Path) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(report).map_err(|e| e.to_string())?;
+    fs::write(path, json + "\n").map_err(|e| format!("could not write {}: {}", path.display(), e))
+}
+
+pub fn load_report(path: &Path) -> Result<Report, String> {
+    let json = fs::read_to_string(path)
+        .map_err(|e| format!("could not read {}: {}", path.display(), e))?;
+    serde_json::from_str(&json).map_err(|e| format!("invalid baseline {}: {}", path.display(), e))
+}
+
+fn exceeds(baseline: u64, current: u64, threshold: f64) -> bool {
+    current as f64 > baseline as f64 * (1.0 + threshold / 100.0)
+}
+
+fn is_regression(baseline: &Stats, current: &Stats, threshold: f64) -> bool {
+    exceeds(baseline.median_ns, current.median_ns, threshold)
+}
+
+/// Whether a stage allocates more in total or at its peak, if both reports counted allocations.
+fn is_memory_regression(baseline: &Stats, current: &Stats, threshold: f64) -> bool {
+    let (Some(base), Some(current)) = (baseline.allocs, current.allocs) else {
+        return false;
+    };
+    exceeds(base.bytes, current.bytes, threshold)
+        || exceeds(base.peak_bytes, current.peak_bytes, threshold)
+}
+
+/// Prints how each median changed since the baseline, returning whether any regressed.
+///
+/// Memory counts as regressed like time does, when both reports counted allocations.
+/// Stages that only appear in one of the reports are skipped.
+pub fn compare(baseline: &Report, report: &Report, threshold: f64) -> bool {
+    let mut regressed = false;
+    println!(
+        "{:>3}  {:<6}  {:>10}  {:>10}  {:>8}  status",
+        "day", "stage", "baseline", "median", "change"
+    );
+    for result in &report.days {
+        let Some(base) = baseline.days.iter().find(|b| b.day == result.day) else {
+            continue;
+        };
+        for ((stage, current), (_, base)) in result.stages().into_iter().zip(base.stages()) {
+            let (Some(current), Some(base)) = (current, base) else {
+                continue;
+            };
+            let change = (current.median_ns as f64 / base.median_ns.max(1) as f64 - 1.0) * 100.0;
+            let status = match (
+                is_regression(&base, &current, threshold),
+                is_memory_regression(&base, &current, threshold),
+            ) {
+                (false, false) => "ok",
+                (true, false) => "REGRESSION",
+                (false, true) => "REGRESSION (memory)",
+                (true, true) => "REGRESSION (time and memory)",
+            };
+            regressed |= status != "ok";
+            println!(
+                "{:>3}  {:<6}  {:>10}  {:>10}  {:>+7.1}%  {}",
+                result.day,
+                stage,
+                ns(base.median_ns),
+                ns(current.median_ns),
+                change,
+                status,
+            );
+        }
+    }
+    regressed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let mut samples = [4, 1, 3, 2].map(Duration::from_nanos);
+        let stats = Stats::new(&mut samples);
+        assert_eq!(
+            Stats {
+                min_ns: 1,
+                median_ns: 2,
+                mean_ns: 2,
+                stddev_ns: 1,
+                allocs: None,
+            },
+            stats
+        );
+
+        let slower = Stats {
+            median_ns: 120,
+            ..stats
+        };
+        let base = Stats {
+            median_ns: 100,
+            ..stats
+        };
+        assert!(is_regression(&base, &slower, 10.0));
+        assert!(!is_regression(&base, &slower, 25.0));
+
+        let allocs = |peak_bytes| Allocs {
+            count: 1,
+            bytes: 1000,
+            peak_bytes,
+        };
+        let hungrier = Stats {
+            allocs: Some(allocs(1200)),
+            ..base
+        };
+        assert!(!is_memory_regression(&base, &hungrier, 10.0));
+        let base = Stats {
+            allocs: Some(allocs(1000)),
+            ..base
+        };
+        assert!(is_memory_regression(&base, &hungrier, 10.0));
+        assert!(!is_memory_regression(&base, &hungrier, 25.0));
+    }
+}
@@ -0,0 +1,85 @@
+//! Checking the days against the examples from their puzzle descriptions, for `run --example`.
+
+use std::sync::Arc;
+
+use crate::{DynSolution, Part};
+
+use crate::runner::verify::diff;
+use crate::runner::watchdog::Watchdog;
+
+/// Solves every example of each day under `watchdog`, printing a pass/fail table, and returns
+/// whether any failed.
+pub fn check(
+    solutions: impl Iterator<Item = &'static dyn DynSolution>,
+    parts: &[Part],
+    watchdog: &Watchdog,
+) -> bool {
+    let mut failed = false;
+    let (mut passed, mut total) = (0, 0);
+    println!("{:>3}  {:>7}  {:>4}  status", "day", "example", "part");
+    for solution in solutions {
+        let day = solution.day();
+        let examples = solution.examples();
+        if examples.is_empty() {
+            println!("{:>3}  {:>7}  {:>4}  no examples", day, "", "");
+        }
+        for (i, (input, part_1, part_2)) in examples.into_iter().enumerate() {
+            let example = i + 1;
+            let input = match watchdog.run(move || solution.parse_input(input)) {
+                Ok(Ok(input)) => Arc::new(input),
+                Ok(Err(e)) => {
+                    println!("{:>3}  {:>7}  {:>4}  ERROR    {}", day, example, "", e);
+                    failed = true;
+                    continue;
+                }
+                Err(failure) => {
+                    println!(
+                        "{:>3}  {:>7}  {:>4}  ERROR    parsing {}",
+                        day, example, "", failure
+                    );
+                    failed = true;
+                    continue;
+                }
+            };
+            for (part, expected) in Part::ALL.into_iter().zip([part_1, part_2]) {
+                let Some(expected) = expected.filter(|_| parts.contains(&part)) else {
+                    continue;
+                };
+                total += 1;
+                let got = {
+                    let input = input.clone();
+                    match watchdog.run(move || solution.solve(part, &input)) {
+                        Ok(got) => got,
+                        Err(failure) => {
+                            failed = true;
+                            println!(
+                                "{:>3}  {:>7}  {:>4}  FAILED   {}",
+                                day,
+                                example,
+                                part.number(),
+                                failure
+                            );
+                            continue;
+                        }
+                    }
+                };
+                if got == expected {
+                    passed += 1;
+                    println!("{:>3}  {:>7}  {:>4}  pass", day, example, part.number());
+                } else {
+                    failed = true;
+                    let diff = diff(&expected.to_string(), &got.to_string());
+                    println!(
+                        "{:>3}  {:>7}  {:>4}  FAIL     {}",
+                        day,
+                        example,
+                        part.number(),
+                        diff
+                    );
+                }
+            }
+        }
+    }
+    println!("\n{} of {} answers passed", passed, total);
+    failed
+}
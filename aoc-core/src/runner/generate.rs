@@ -0,0 +1,34 @@
+use std::{fs, path::PathBuf};
+
+use crate::Year;
+
+pub struct GenerateArgs {
+    pub seed: u64,
+    pub scale: usize,
+    /// Where to write the inputs, which defaults to `input/generated/<year>`.
+    pub out: Option<PathBuf>,
+}
+
+impl Default for GenerateArgs {
+    fn default() -> Self {
+        GenerateArgs {
+            seed: 0,
+            scale: 1,
+            out: None,
+        }
+    }
+}
+
+/// Generates a day's input and writes it to `dayN.txt` in the output directory.
+pub fn write_input(args: &GenerateArgs, year: &Year, day: u8) -> Result<PathBuf, String> {
+    let input = (year.generate)(day, args.seed, args.scale)
+        .ok_or_else(|| format!("no generator for day {}", day))?;
+    let out = match &args.out {
+        Some(out) => out.clone(),
+        None => PathBuf::from(format!("input/generated/{}", year.year)),
+    };
+    fs::create_dir_all(&out).map_err(|e| format!("could not create {}: {}", out.display(), e))?;
+    let path = out.join(format!("day{}.txt", day));
+    fs::write(&path, input).map_err(|e| format!("could not write {}: {}", path.display(), e))?;
+    Ok(path)
+}
@@ -0,0 +1,175 @@
+//! Counting what each stage allocates, in builds with the `alloc-stats` feature.
+//!
+//! The counts are kept per thread, so days and parts solved at once by `--jobs` don't mix up
+//! their numbers. Memory freed on a different thread than allocated it isn't subtracted from
+//! that thread's live bytes, but none of the solutions share allocations between threads.
+
+#[cfg(feature = "alloc-stats")]
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use serde::{Deserialize, Serialize};
+
+/// What one stage allocated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Allocs {
+    /// How many allocations were made, counting each reallocation as one.
+    pub count: u64,
+    /// The total size of every allocation.
+    pub bytes: u64,
+    /// The most memory the stage had allocated at once, beyond what it started with.
+    pub peak_bytes: u64,
+}
+
+impl std::fmt::Display for Allocs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocs, {}, peak {}",
+            self.count,
+            bytes(self.bytes),
+            bytes(self.peak_bytes)
+        )
+    }
+}
+
+pub fn bytes(n: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut size = n as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    match unit {
+        0 => format!("{} B", n),
+        _ => format!("{:.1} {}", size, UNITS[unit]),
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Counter {
+    count: u64,
+    bytes: u64,
+    /// Can go negative when the stage frees memory allocated before it started.
+    live: i64,
+    peak: i64,
+}
+
+const ZERO: Counter = Counter {
+    count: 0,
+    bytes: 0,
+    live: 0,
+    peak: 0,
+};
+
+thread_local! {
+    // const-initialized and without a destructor, so using it never allocates
+    static COUNTER: Cell<Counter> = const { Cell::new(ZERO) };
+}
+
+#[cfg(feature = "alloc-stats")]
+fn record(allocated: usize, freed: usize) {
+    // during thread teardown the counter may already be gone, and then there's nothing to do
+    let _ = COUNTER.try_with(|counter| {
+        let mut c = counter.get();
+        if allocated > 0 {
+            c.count += 1;
+            c.bytes += allocated as u64;
+        }
+        c.live += allocated as i64 - freed as i64;
+        c.peak = c.peak.max(c.live);
+        counter.set(c);
+    });
+}
+
+/// The system allocator, counting each thread's allocations.
+#[cfg(feature = "alloc-stats")]
+pub struct Counting;
+
+#[cfg(feature = "alloc-stats")]
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(0, layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// Runs `f`, returning what it allocated on this thread if allocations are being counted.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Allocs>) {
+    let outer = COUNTER.with(|counter| counter.replace(ZERO));
+    let out = f();
+    let inner = COUNTER.with(|counter| counter.get());
+    // carry this stage's allocations over to any measurement it is nested in
+    COUNTER.with(|counter| {
+        counter.set(Counter {
+            count: outer.count + inner.count,
+            bytes: outer.bytes + inner.bytes,
+            live: outer.live + inner.live,
+            peak: outer.peak.max(outer.live + inner.peak),
+        })
+    });
+
+    let allocs = Allocs {
+        count: inner.count,
+        bytes: inner.bytes,
+        peak_bytes: inner.peak.max(0) as u64,
+    };
+    (out, cfg!(feature = "alloc-stats").then_some(allocs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::hint::black_box;
+
+    #[test]
+    fn test() {
+        assert_eq!("1023 B", bytes(1023));
+        assert_eq!("1.5 KiB", bytes(1536));
+        assert_eq!("3.0 GiB", bytes(3 << 30));
+
+        // black_box stops the allocations from being optimized away
+        let (len, allocs) = measure(|| {
+            drop(black_box(vec![0u8; 100]));
+            black_box(vec![0u8; 1000]).len()
+        });
+        assert_eq!(1000, len);
+        assert_eq!(cfg!(feature = "alloc-stats"), allocs.is_some());
+        if let Some(allocs) = allocs {
+            assert_eq!(2, allocs.count);
+            assert_eq!(1100, allocs.bytes);
+            assert_eq!(1000, allocs.peak_bytes);
+            assert_eq!("2 allocs, 1.1 KiB, peak 1000 B", allocs.to_string());
+        }
+    }
+}
@@ -0,0 +1,869 @@
+//! The command-line runner, which each year's binary hands its registered [`Year`]s to.
+
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    io::{self, Read},
+    mem,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    sync::{
+        mpsc::{self, Sender},
+        Arc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::*;
+
+use bench::BenchArgs;
+use generate::GenerateArgs;
+use memory::Allocs;
+use output::Format;
+use pool::{Job, Pool};
+use verify::{Manifest, VerifyArgs};
+use watchdog::{quiet_panics, Failure, Watchdog};
+
+mod batch;
+mod bench;
+mod example;
+mod generate;
+mod memory;
+mod output;
+mod pool;
+mod trace;
+mod verify;
+mod watch;
+mod watchdog;
+
+const USAGE: &str = "\
+Usage: bin [run] [OPTIONS] [RUN OPTIONS]
+       bin bench [OPTIONS] [BENCH OPTIONS]
+       bin verify [OPTIONS] [VERIFY OPTIONS]
+       bin generate [--day <N>] [GENERATE OPTIONS]
+       bin watch --day <N> [OPTIONS]
+
+Commands:
+  run                solve each selected day once and print the answers [default]
+  bench              time each selected day repeatedly and report statistics
+  verify             check each selected day's answers against the answers manifest
+  generate           write random inputs for each selected day, for stress and scale testing
+  watch              re-run a day whenever its source or input changes, rebuilding it with
+                     cargo and showing the new answers and times next to the previous ones
+
+Options:
+  --year <N>         run the given year's puzzles [default: the latest one built in]
+  --day <N>          only run the given day (1-25)
+  --part <1|2>       only run the given part
+  --input <PATH>     read the input from PATH, or from stdin if PATH is '-' (requires --day)
+  --input-dir <DIR>  directory containing the dayN.txt inputs [default: input/<year>]
+  --jobs <N>         solve up to N days and parts at once (not with bench)
+                     [default: 1, or one per CPU with --inputs-dir]
+  --param <NAME=N>   solve with a different value for one of the day's parameters, which
+                     can be given more than once (requires --day, not with verify or generate)
+  --params           list the parameters of each selected day
+  --timeout <SECS>   give up on a part once it has run for SECS seconds, reporting it as timed
+                     out and carrying on with the rest (not with bench or generate)
+  -h, --help         print this help
+
+Run options:
+  --trace <DIR>      write each step of the simulating days (11, 13, 20, 23 and 25) to DIR as
+                     text frames, PBM/PGM images and an animated GIF
+  --inputs-dir <DIR> solve every file in DIR as an input and print a table of the results,
+                     flagging inputs that fail to parse, panic or time out (requires --day)
+  --format <FORMAT>  print the results as text, json or csv, with one record per part giving
+                     its day, answer, parse and solve times and status [default: text]
+  --example          solve the examples from the puzzle descriptions instead of the inputs,
+                     checking the answers they give
+
+Bench options:
+  --warmup <N>       untimed iterations before measuring [default: 3]
+  --iterations <N>   measured iterations [default: 10]
+  --save <PATH>      write the results as JSON to PATH
+  --baseline <PATH>  compare the medians against results written by '--save'
+  --threshold <PCT>  how much slower a median may get before it fails the run [default: 10]
+
+Verify options:
+  --answers <PATH>   the answers manifest [default: <input-dir>/answers.toml]
+  --record           write the computed answers to the manifest instead of checking them
+
+Generate options:
+  --seed <N>         the same seed always generates the same inputs [default: 0]
+  --scale <N>        how many times larger than a puzzle input to make them [default: 1]
+  --out <DIR>        directory to write the dayN.txt inputs to [default: input/generated/<year>]
+
+Built with the 'alloc-stats' feature, run and bench also report how many allocations each
+stage makes, how many bytes they total and the most that was allocated at once.";
+
+enum Input {
+    Stdin,
+    File(PathBuf),
+}
+
+#[derive(Default)]
+struct RunArgs {
+    trace: Option<PathBuf>,
+    /// Solve every input in this directory instead of the day's own.
+    inputs_dir: Option<PathBuf>,
+    format: Format,
+    example: bool,
+}
+
+enum Command {
+    Run(RunArgs),
+    Bench(BenchArgs),
+    Verify(VerifyArgs),
+    Generate(GenerateArgs),
+    Watch,
+}
+
+struct Args {
+    command: Command,
+    year: &'static Year,
+    jobs: Option<usize>,
+    day: Option<u8>,
+    params: Params,
+    /// Print the selected days' parameters instead of running the command.
+    list_params: bool,
+    part: Option<Part>,
+    /// How long each part may run for, if there's a limit.
+    timeout: Option<Duration>,
+    input: Option<Input>,
+    /// Where the `dayN.txt` inputs are, which defaults to `input/<year>`.
+    input_dir: Option<PathBuf>,
+}
+
+impl Args {
+    fn input_dir(&self) -> PathBuf {
+        match &self.input_dir {
+            Some(dir) => dir.clone(),
+            None => PathBuf::from(format!("input/{}", self.year.year)),
+        }
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(arg: &str, value: String) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid value '{}' for '{}'", value, arg))
+}
+
+fn parse_args(
+    args: impl Iterator<Item = String>,
+    years: &[&'static Year],
+) -> Result<Option<Args>, String> {
+    let mut args = args.peekable();
+    let command = match args.peek().map(String::as_str) {
+        Some("bench") => Command::Bench(BenchArgs::default()),
+        Some("verify") => Command::Verify(VerifyArgs {
+            record: false,
+            answers: None,
+        }),
+        Some("generate") => Command::Generate(GenerateArgs::default()),
+        Some("watch") => Command::Watch,
+        _ => Command::Run(RunArgs::default()),
+    };
+    if let Some("run" | "bench" | "verify" | "generate" | "watch") = args.peek().map(String::as_str)
+    {
+        args.next();
+    }
+    parse_options(args, command, years)
+}
+
+fn parse_options(
+    mut args: impl Iterator<Item = String>,
+    command: Command,
+    years: &[&'static Year],
+) -> Result<Option<Args>, String> {
+    let Some(&latest) = years.iter().max_by_key(|year| year.year) else {
+        return Err("no years are built in".to_string());
+    };
+    let mut out = Args {
+        command,
+        year: latest,
+        jobs: None,
+        day: None,
+        params: Params::default(),
+        list_params: false,
+        part: None,
+        timeout: None,
+        input: None,
+        input_dir: None,
+    };
+    // parameters are looked up once the day is known
+    let mut settings = vec![];
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("missing value for '{}'", arg));
+        match arg.as_str() {
+            "--year" => {
+                let year = value()?;
+                out.year = match years.iter().find(|y| y.year.to_string() == year) {
+                    Some(year) => *year,
+                    None => {
+                        let built: Vec<_> = years.iter().map(|y| y.year.to_string()).collect();
+                        return Err(format!(
+                            "invalid year '{}', expected {}",
+                            year,
+                            built.join(", ")
+                        ));
+                    }
+                }
+            }
+            "--day" => {
+                let day = value()?;
+                match day.parse() {
+                    Ok(n @ 1..=25) => out.day = Some(n),
+                    _ => return Err(format!("invalid day '{}', expected 1-25", day)),
+                }
+            }
+            "--part" => {
+                let part = value()?;
+                match part.as_str() {
+                    "1" => out.part = Some(Part::One),
+                    "2" => out.part = Some(Part::Two),
+                    _ => return Err(format!("invalid part '{}', expected 1 or 2", part)),
+                }
+            }
+            "--input" => {
+                out.input = Some(match value()?.as_str() {
+                    "-" => Input::Stdin,
+                    path => Input::File(PathBuf::from(path)),
+                })
+            }
+            "--input-dir" => out.input_dir = Some(PathBuf::from(value()?)),
+            "--param" => settings.push(value()?),
+            "--timeout" => {
+                let value = value()?;
+                if let Command::Bench(_) | Command::Generate(_) = out.command {
+                    return Err("'--timeout' can't be used with 'bench' or 'generate'".to_string());
+                }
+                match parse_number::<f64>(&arg, value)? {
+                    secs if secs > 0.0 && secs.is_finite() => {
+                        out.timeout = Some(Duration::from_secs_f64(secs))
+                    }
+                    _ => return Err("'--timeout' must be more than 0".to_string()),
+                }
+            }
+            "--params" => out.list_params = true,
+            "--jobs" => {
+                let value = value()?;
+                if let Command::Bench(_) = out.command {
+                    // timings are only comparable when nothing else is running
+                    return Err("'--jobs' can't be used with 'bench'".to_string());
+                }
+                match parse_number(&arg, value)? {
+                    0 => return Err("'--jobs' must be at least 1".to_string()),
+                    n => out.jobs = Some(n),
+                }
+            }
+            "--record" | "--answers" => {
+                let Command::Verify(verify) = &mut out.command else {
+                    return Err(format!("'{}' can only be used with 'verify'", arg));
+                };
+                match arg.as_str() {
+                    "--record" => verify.record = true,
+                    _ => verify.answers = Some(PathBuf::from(value()?)),
+                }
+            }
+            "--seed" | "--scale" | "--out" => {
+                let value = value()?;
+                let Command::Generate(generate) = &mut out.command else {
+                    return Err(format!("'{}' can only be used with 'generate'", arg));
+                };
+                match arg.as_str() {
+                    "--seed" => generate.seed = parse_number(&arg, value)?,
+                    "--scale" => match parse_number(&arg, value)? {
+                        0 => return Err("'--scale' must be at least 1".to_string()),
+                        n => generate.scale = n,
+                    },
+                    _ => generate.out = Some(PathBuf::from(value)),
+                }
+            }
+            "--trace" | "--inputs-dir" | "--format" => {
+                let value = value()?;
+                let Command::Run(run) = &mut out.command else {
+                    return Err(format!("'{}' can only be used with 'run'", arg));
+                };
+                match arg.as_str() {
+                    "--trace" => run.trace = Some(PathBuf::from(value)),
+                    "--inputs-dir" => run.inputs_dir = Some(PathBuf::from(value)),
+                    _ => {
+                        run.format = Format::parse(&value).ok_or(format!(
+                            "invalid format '{}', expected text, json or csv",
+                            value
+                        ))?
+                    }
+                }
+            }
+            "--example" => {
+                let Command::Run(run) = &mut out.command else {
+                    return Err("'--example' can only be used with 'run'".to_string());
+                };
+                run.example = true;
+            }
+            "-h" | "--help" => return Ok(None),
+            "--warmup" | "--iterations" | "--save" | "--baseline" | "--threshold" => {
+                let value = value()?;
+                let Command::Bench(bench) = &mut out.command else {
+                    return Err(format!("'{}' can only be used with 'bench'", arg));
+                };
+                match arg.as_str() {
+                    "--warmup" => bench.warmup = parse_number(&arg, value)?,
+                    "--iterations" => match parse_number(&arg, value)? {
+                        0 => return Err("'--iterations' must be at least 1".to_string()),
+                        n => bench.iterations = n,
+                    },
+                    "--save" => bench.save = Some(PathBuf::from(value)),
+                    "--baseline" => bench.baseline = Some(PathBuf::from(value)),
+                    _ => match parse_number::<f64>(&arg, value)? {
+                        percent if percent >= 0.0 && percent.is_finite() => {
+                            bench.threshold = percent
+                        }
+                        _ => return Err("'--threshold' must be 0 or more".to_string()),
+                    },
+                }
+            }
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    // the year can come after the day, so the day is only checked once both are known
+    if let Some(day) = out.day.filter(|&day| out.year.solution(day).is_none()) {
+        return Err(format!(
+            "day {} of {} isn't compiled in, build with the 'day{}' feature",
+            day, out.year.year, day
+        ));
+    }
+    if let (Command::Generate(_), true) = (&out.command, out.part.is_some() || out.input.is_some())
+    {
+        return Err("'generate' only takes '--day' and the generate options".to_string());
+    }
+    if !settings.is_empty() {
+        let Some(day) = out.day else {
+            return Err("'--param' can only be used together with '--day'".to_string());
+        };
+        match &out.command {
+            Command::Verify(_) | Command::Generate(_) => {
+                return Err("'--param' can't be used with 'verify' or 'generate'".to_string())
+            }
+            Command::Run(RunArgs { example: true, .. }) => {
+                return Err("'--param' can't be used with '--example'".to_string())
+            }
+            _ => {}
+        }
+        let params = out.year.solution(day).unwrap().params();
+        for setting in &settings {
+            out.params
+                .set(params, setting)
+                .map_err(|e| format!("day {}: {}", day, e))?;
+        }
+    }
+    if let Command::Watch = out.command {
+        if out.day.is_none() {
+            return Err("'watch' needs a '--day' to watch".to_string());
+        }
+        if let Some(Input::Stdin) = out.input {
+            return Err("'watch' can't read the input from stdin".to_string());
+        }
+    }
+    if out.input.is_some() && out.day.is_none() {
+        return Err("'--input' can only be used together with '--day'".to_string());
+    }
+    if let Command::Run(RunArgs {
+        inputs_dir: Some(_),
+        trace,
+        format,
+        example,
+    }) = &out.command
+    {
+        if *format != Format::Text {
+            return Err("'--format' can't be used with '--inputs-dir'".to_string());
+        }
+        if out.day.is_none() {
+            return Err("'--inputs-dir' can only be used together with '--day'".to_string());
+        }
+        if out.input.is_some() || trace.is_some() || *example {
+            return Err(
+                "'--inputs-dir' can't be used with '--input', '--trace' or '--example'".to_string(),
+            );
+        }
+    }
+    if let Command::Run(RunArgs {
+        example: true,
+        trace,
+        format,
+        ..
+    }) = &out.command
+    {
+        if out.input.is_some() || trace.is_some() || *format != Format::Text {
+            return Err(
+                "'--example' can't be used with '--input', '--trace' or '--format'".to_string(),
+            );
+        }
+        if out.timeout.is_some() {
+            return Err("'--timeout' can't be used with '--example'".to_string());
+        }
+    }
+
+    Ok(Some(out))
+}
+
+fn read_file(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("could not read {}: {}", path.display(), e))
+}
+
+fn read_input(args: &Args, day: u8) -> Result<String, String> {
+    match &args.input {
+        Some(Input::Stdin) => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|e| format!("could not read stdin: {}", e))?;
+            Ok(input)
+        }
+        Some(Input::File(path)) => read_file(path),
+        None => read_file(&args.input_dir().join(format!("day{}.txt", day))),
+    }
+}
+
+/// The outcome of parsing and solving one day.
+#[derive(Default)]
+struct DayRun {
+    day: u8,
+    parse_time: Duration,
+    parts: Vec<(Part, Answer, Duration)>,
+    error: Option<String>,
+    /// What parsing and each part allocated, when built with the `alloc-stats` feature.
+    parse_allocs: Option<Allocs>,
+    part_allocs: BTreeMap<Part, Allocs>,
+    /// The parts that panicked or timed out, which are left with [`Answer::None`].
+    part_failures: BTreeMap<Part, Failure>,
+}
+
+impl DayRun {
+    fn failed(&self) -> bool {
+        self.error.is_some() || !self.part_failures.is_empty()
+    }
+
+    fn cpu_time(&self) -> Duration {
+        self.parse_time
+            + self
+                .parts
+                .iter()
+                .map(|(_, _, time)| *time)
+                .sum::<Duration>()
+    }
+
+    fn print(&self) {
+        println!("day {}", self.day);
+        if let Some(e) = &self.error {
+            eprintln!("error: {}\n", e);
+            return;
+        }
+        let allocs = |allocs: Option<&Allocs>| match allocs {
+            Some(allocs) => format!(" ({})", allocs),
+            None => String::new(),
+        };
+        println!(
+            "  parsed in {:.2?}{}",
+            self.parse_time,
+            allocs(self.parse_allocs.as_ref())
+        );
+        for (part, answer, time) in &self.parts {
+            let allocs = allocs(self.part_allocs.get(part));
+            match self.part_failures.get(part) {
+                Some(failure) => println!(
+                    "  part {} in {:.2?}{}: FAILED, {}",
+                    part, time, allocs, failure
+                ),
+                None => println!("  part {} in {:.2?}{}: {}", part, time, allocs, answer),
+            }
+        }
+        println!("done in {:?}\n", self.cpu_time());
+    }
+}
+
+/// Runs `f` under `watchdog`, timing it and counting its allocations on whichever thread it runs.
+fn measure<T: Send + 'static>(
+    watchdog: &Watchdog,
+    f: impl FnOnce() -> T + Send + 'static,
+) -> (Result<T, Failure>, Duration, Option<Allocs>) {
+    let instant = Instant::now();
+    let measured = watchdog.run(move || {
+        let instant = Instant::now();
+        let (value, allocs) = memory::measure(f);
+        (value, instant.elapsed(), allocs)
+    });
+    match measured {
+        Ok((value, time, allocs)) => (Ok(value), time, allocs),
+        Err(failure) => (Err(failure), instant.elapsed(), None),
+    }
+}
+
+/// Parses a day's input, then queues a job to solve each part under `watchdog`.
+///
+/// The finished [`DayRun`] is sent along with `index` once every part has been solved.
+fn run_day<'a>(
+    pool: &Pool<'a>,
+    (args, watchdog): (&'a Args, &'a Watchdog),
+    solution: &'static dyn DynSolution,
+    parts: &'a [Part],
+    (index, done): (usize, &'a Sender<(usize, DayRun)>),
+) {
+    let mut run = DayRun {
+        day: solution.day(),
+        ..DayRun::default()
+    };
+    let input = match read_input(args, run.day) {
+        Ok(input) => input,
+        Err(e) => {
+            run.error = Some(e);
+            done.send((index, run)).unwrap();
+            return;
+        }
+    };
+
+    let (input, time, allocs) = measure(watchdog, move || solution.parse_input(&input));
+    run.parse_time = time;
+    run.parse_allocs = allocs;
+    let input = match input {
+        Ok(Ok(input)) => Arc::new(input),
+        Ok(Err(e)) => {
+            run.error = Some(e.to_string());
+            done.send((index, run)).unwrap();
+            return;
+        }
+        Err(failure) => {
+            run.error = Some(format!("parsing {}", failure));
+            done.send((index, run)).unwrap();
+            return;
+        }
+    };
+
+    let trace_dir = match &args.command {
+        Command::Run(RunArgs {
+            trace: Some(dir), ..
+        }) => Some(dir),
+        _ => None,
+    };
+    let run = Arc::new(Mutex::new(run));
+    // jobs are queued at the front, so this makes part 1 start first
+    for &part in parts.iter().rev() {
+        let (input, run) = (input.clone(), run.clone());
+        // the part may outlive this run if it times out, so it gets its own copies
+        let (trace_dir, params) = (trace_dir.cloned(), args.params.clone());
+        pool.spawn(move |_| {
+            let (answer, time, allocs) = measure(watchdog, move || {
+                let traced =
+                    trace_dir.and_then(|dir| trace::solve(&dir, solution, part, &input, &params));
+                traced.unwrap_or_else(|| Ok(solution.solve_with(part, &input, &params)))
+            });
+
+            let mut run = run.lock().unwrap();
+            if let Some(allocs) = allocs {
+                run.part_allocs.insert(part, allocs);
+            }
+            match answer {
+                Ok(Ok(answer)) => run.parts.push((part, answer, time)),
+                Ok(Err(e)) => {
+                    run.error.get_or_insert(e);
+                    run.parts.push((part, Answer::None, time));
+                }
+                Err(failure) => {
+                    run.part_failures.insert(part, failure);
+                    run.parts.push((part, Answer::None, time));
+                }
+            }
+            if run.parts.len() == parts.len() {
+                run.parts.sort_by_key(|(part, _, _)| *part);
+                done.send((index, mem::take(&mut *run))).unwrap();
+            }
+        });
+    }
+}
+
+fn parts(args: &Args) -> Vec<Part> {
+    Part::ALL
+        .into_iter()
+        .filter(|&part| args.part.unwrap_or(part) == part)
+        .collect()
+}
+
+/// Solves every selected day on `args.jobs` threads, passing each to `finished` in order of day.
+///
+/// A day or part that panics or runs out of time is reported as failed, and the rest carry on.
+fn solve_days(args: &Args, mut finished: impl FnMut(DayRun)) {
+    let solutions: Vec<_> = selected(args).collect();
+    let parts = &parts(args);
+    let watchdog = &Watchdog::new(args.timeout);
+    let (done, receiver) = mpsc::channel();
+
+    // the panics are reported with the results, so don't also print them as they happen
+    quiet_panics(|| {
+        thread::scope(|s| {
+            s.spawn(move || {
+                let done = &done;
+                let days = solutions.into_iter().enumerate().map(|(i, solution)| {
+                    let job: Job = Box::new(move |pool| {
+                        run_day(pool, (args, watchdog), solution, parts, (i, done))
+                    });
+                    job
+                });
+                Pool::run(args.jobs.unwrap_or(1), days);
+            });
+
+            // days can finish in any order, so hold on to them until the earlier ones are done
+            let mut waiting = BTreeMap::new();
+            let mut next = 0;
+            for (i, run) in receiver {
+                waiting.insert(i, run);
+                while let Some(run) = waiting.remove(&next) {
+                    finished(run);
+                    next += 1;
+                }
+            }
+        })
+    });
+}
+
+fn run(args: &Args, run_args: &RunArgs) -> bool {
+    if let Some(dir) = &run_args.inputs_dir {
+        return run_batch(args, dir);
+    }
+    if run_args.example {
+        let watchdog = Watchdog::new(None);
+        return quiet_panics(|| example::check(selected(args), &parts(args), &watchdog));
+    }
+    if let Some(dir) = &run_args.trace {
+        if let Err(e) = fs::create_dir_all(dir) {
+            eprintln!("error: could not create {}: {}", dir.display(), e);
+            return true;
+        }
+    }
+
+    if run_args.format != Format::Text {
+        let mut runs = vec![];
+        solve_days(args, |run| runs.push(run));
+        let records = output::records(&runs);
+        match run_args.format {
+            Format::Json => println!("{}", output::to_json(&records)),
+            _ => print!("{}", output::to_csv(&records)),
+        }
+        return runs.iter().any(DayRun::failed);
+    }
+
+    let instant = Instant::now();
+    let mut failed = false;
+    let mut cpu_time = Duration::ZERO;
+    solve_days(args, |run| {
+        run.print();
+        failed |= run.failed();
+        cpu_time += run.cpu_time();
+    });
+
+    println!("done in {:?} (cpu time {:?})", instant.elapsed(), cpu_time);
+    failed
+}
+
+fn run_batch(args: &Args, dir: &Path) -> bool {
+    let solution = selected(args).next().unwrap();
+    let jobs = args
+        .jobs
+        .unwrap_or_else(|| thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get));
+    let watchdog = Watchdog::new(args.timeout);
+    match batch::solve_dir(solution, &parts(args), &args.params, &watchdog, dir, jobs) {
+        Ok(runs) => batch::print_report(&parts(args), &runs),
+        Err(e) => {
+            eprintln!("error: {}", e);
+            true
+        }
+    }
+}
+
+fn verify(args: &Args, verify: &VerifyArgs) -> bool {
+    let path = match &verify.answers {
+        Some(path) => path.clone(),
+        None => args.input_dir().join("answers.toml"),
+    };
+    let mut runs = vec![];
+    solve_days(args, |run| runs.push(run));
+
+    if !verify.record {
+        return match Manifest::load(&path) {
+            Ok(manifest) => verify::check(&manifest, &runs),
+            Err(e) => {
+                eprintln!("error: {}", e);
+                true
+            }
+        };
+    }
+
+    let mut manifest = match Manifest::load_or_default(&path) {
+        Ok(manifest) => manifest,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return true;
+        }
+    };
+    let mut failed = false;
+    let mut recorded = 0;
+    for run in &runs {
+        if let Some(e) = &run.error {
+            eprintln!("error: {}", e);
+            failed = true;
+        }
+        for (part, failure) in &run.part_failures {
+            eprintln!("error: day {} part {} {}", run.day, part, failure);
+            failed = true;
+        }
+        for (part, answer, _) in run.parts.iter().filter(|(_, a, _)| *a != Answer::None) {
+            manifest.record(run.day, *part, answer);
+            recorded += 1;
+        }
+    }
+    manifest.sort();
+    if let Err(e) = manifest.save(&path) {
+        eprintln!("error: {}", e);
+        return true;
+    }
+    println!("recorded {} answers in {}", recorded, path.display());
+    failed
+}
+
+fn bench(args: &Args, bench: &BenchArgs) -> bool {
+    let mut failed = false;
+    let mut report = bench::Report {
+        warmup: bench.warmup,
+        iterations: bench.iterations,
+        days: vec![],
+    };
+    let watchdog = Watchdog::new(None);
+    // the panics are reported with the results, so don't also print them as they happen
+    quiet_panics(|| {
+        for solution in selected(args) {
+            let result = read_input(args, solution.day()).and_then(|input| {
+                bench::bench_day(
+                    bench,
+                    &watchdog,
+                    solution,
+                    &parts(args),
+                    &args.params,
+                    input,
+                )
+            });
+            match result {
+                Ok(result) => {
+                    failed |= !result.failures.is_empty();
+                    report.days.push(result);
+                }
+                Err(e) => {
+                    eprintln!("error: day {}: {}", solution.day(), e);
+                    failed = true;
+                }
+            }
+        }
+    });
+    bench::print_report(&report);
+
+    if let Some(path) = &bench.save {
+        if let Err(e) = bench::save_report(&report, path) {
+            eprintln!("error: {}", e);
+            failed = true;
+        }
+    }
+    if let Some(path) = &bench.baseline {
+        println!();
+        match bench::load_report(path) {
+            Ok(baseline) => failed |= bench::compare(&baseline, &report, bench.threshold),
+            Err(e) => {
+                eprintln!("error: {}", e);
+                failed = true;
+            }
+        }
+    }
+    failed
+}
+
+fn generate(args: &Args, generate: &GenerateArgs) -> bool {
+    let mut failed = false;
+    for solution in selected(args) {
+        match generate::write_input(generate, args.year, solution.day()) {
+            Ok(path) => println!("wrote {}", path.display()),
+            Err(e) => {
+                eprintln!("error: {}", e);
+                failed = true;
+            }
+        }
+    }
+    failed
+}
+
+fn list_params(args: &Args) {
+    for solution in selected(args) {
+        match solution.params() {
+            [] => println!("day {}: no parameters", solution.day()),
+            params => {
+                println!("day {}:", solution.day());
+                for param in params {
+                    println!("  {}", param);
+                }
+            }
+        }
+    }
+}
+
+fn selected(args: &Args) -> impl Iterator<Item = &'static dyn DynSolution> + '_ {
+    args.year
+        .solutions
+        .iter()
+        .copied()
+        .filter(|s| args.day.unwrap_or(s.day()) == s.day())
+}
+
+fn watch(args: &Args, forwarded: &[String]) -> ! {
+    let day = args.day.unwrap();
+    let dir = Path::new(args.year.source_dir).join(format!("day_{}", day));
+    let input = match &args.input {
+        Some(Input::File(path)) => path.clone(),
+        _ => args.input_dir().join(format!("day{}.txt", day)),
+    };
+    watch::watch(day, &dir, &input, forwarded)
+}
+
+/// Runs the command given on the command line against one of `years`, the latest by default.
+pub fn main(years: &[&'static Year]) -> ExitCode {
+    let argv: Vec<String> = env::args().skip(1).collect();
+    let args = match parse_args(argv.iter().cloned(), years) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if args.list_params {
+        list_params(&args);
+        return ExitCode::SUCCESS;
+    }
+
+    let failed = match &args.command {
+        Command::Run(run_args) => run(&args, run_args),
+        Command::Bench(bench_args) => bench(&args, bench_args),
+        Command::Verify(verify_args) => verify(&args, verify_args),
+        Command::Generate(generate_args) => generate(&args, generate_args),
+        // each run is given the same options, apart from the command itself
+        Command::Watch => watch(&args, &argv[1..]),
+    };
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
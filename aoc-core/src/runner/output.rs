@@ -0,0 +1,254 @@
+//! Machine-readable results for `run --format json|csv`.
+//!
+//! Both formats hold one record per solved part, or one per day that couldn't be solved, with
+//! these fields:
+//!
+//! - `day`: the day, 1-25
+//! - `part`: 1 or 2, or empty if the day failed before any part was solved
+//! - `answer`: the answer as text, keeping the lines of multi-line answers, or empty if there is
+//!   none
+//! - `parse_time_ns`: how long parsing the input took, in nanoseconds
+//! - `solve_time_ns`: how long solving the part took, in nanoseconds, or empty without a part
+//! - `status`: `ok`, `panicked` or `timeout` if the part panicked or ran out of time, or `error`
+//!   if the day couldn't be solved
+//! - `error`: what went wrong, or empty if nothing did
+//! - `allocations`, `allocated_bytes`, `peak_bytes`: what the part allocated, or empty unless
+//!   built with the `alloc-stats` feature
+//!
+//! JSON wraps the records as `{"version": 1, "results": [...]}`, using `null` for empty fields.
+//! CSV has a header row and quotes fields as RFC 4180 does. Fields are only ever added, and the
+//! version goes up if one is changed or removed.
+
+use crate::Answer;
+use serde::{Deserialize, Serialize};
+
+use crate::runner::{watchdog::Failure, DayRun};
+
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl Format {
+    pub fn parse(name: &str) -> Option<Format> {
+        match name {
+            "text" => Some(Format::Text),
+            "json" => Some(Format::Json),
+            "csv" => Some(Format::Csv),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Ok,
+    Panicked,
+    Timeout,
+    Error,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: Option<u8>,
+    pub answer: Option<String>,
+    pub parse_time_ns: u64,
+    pub solve_time_ns: Option<u64>,
+    pub status: Status,
+    pub error: Option<String>,
+    pub allocations: Option<u64>,
+    pub allocated_bytes: Option<u64>,
+    pub peak_bytes: Option<u64>,
+}
+
+#[derive(Serialize)]
+struct Document<'a> {
+    version: u32,
+    results: &'a [Record],
+}
+
+#[derive(Deserialize)]
+struct OwnedDocument {
+    version: u32,
+    results: Vec<Record>,
+}
+
+pub fn records(runs: &[DayRun]) -> Vec<Record> {
+    let mut records = vec![];
+    for run in runs {
+        let parse_time_ns = run.parse_time.as_nanos() as u64;
+        for (part, answer, time) in &run.parts {
+            let allocs = run.part_allocs.get(part);
+            let (status, error) = match run.part_failures.get(part) {
+                None => (Status::Ok, None),
+                Some(Failure::Panicked(message)) => (Status::Panicked, Some(message.clone())),
+                Some(failure) => (Status::Timeout, Some(failure.to_string())),
+            };
+            records.push(Record {
+                day: run.day,
+                part: Some(part.number()),
+                answer: (*answer != Answer::None).then(|| answer.to_string()),
+                parse_time_ns,
+                solve_time_ns: Some(time.as_nanos() as u64),
+                status,
+                error,
+                allocations: allocs.map(|allocs| allocs.count),
+                allocated_bytes: allocs.map(|allocs| allocs.bytes),
+                peak_bytes: allocs.map(|allocs| allocs.peak_bytes),
+            });
+        }
+        if let Some(e) = &run.error {
+            records.push(Record {
+                day: run.day,
+                part: None,
+                answer: None,
+                parse_time_ns,
+                solve_time_ns: None,
+                status: Status::Error,
+                error: Some(e.clone()),
+                allocations: None,
+                allocated_bytes: None,
+                peak_bytes: None,
+            });
+        }
+    }
+    records
+}
+
+pub fn to_json(records: &[Record]) -> String {
+    let document = Document {
+        version: SCHEMA_VERSION,
+        results: records,
+    };
+    serde_json::to_string_pretty(&document).unwrap()
+}
+
+/// Reads back the records written by [`to_json`], as long as they're of the same version.
+pub fn from_json(json: &str) -> Result<Vec<Record>, String> {
+    let document: OwnedDocument =
+        serde_json::from_str(json).map_err(|e| format!("invalid results: {}", e))?;
+    if document.version != SCHEMA_VERSION {
+        return Err(format!(
+            "results are version {}, expected {}",
+            document.version, SCHEMA_VERSION
+        ));
+    }
+    Ok(document.results)
+}
+
+/// Quotes a field if it holds anything that would otherwise end it early.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+pub fn to_csv(records: &[Record]) -> String {
+    let mut out = "day,part,answer,parse_time_ns,solve_time_ns,status,error,\
+                   allocations,allocated_bytes,peak_bytes\r\n"
+        .to_string();
+    let optional = |field: Option<String>| field.unwrap_or_default();
+    for record in records {
+        let status = match record.status {
+            Status::Ok => "ok",
+            Status::Panicked => "panicked",
+            Status::Timeout => "timeout",
+            Status::Error => "error",
+        };
+        let fields = [
+            record.day.to_string(),
+            optional(record.part.map(|part| part.to_string())),
+            optional(record.answer.clone()),
+            record.parse_time_ns.to_string(),
+            optional(record.solve_time_ns.map(|ns| ns.to_string())),
+            status.to_string(),
+            optional(record.error.clone()),
+            optional(record.allocations.map(|n| n.to_string())),
+            optional(record.allocated_bytes.map(|n| n.to_string())),
+            optional(record.peak_bytes.map(|n| n.to_string())),
+        ];
+        let fields: Vec<_> = fields.iter().map(|field| csv_field(field)).collect();
+        out += &fields.join(",");
+        out += "\r\n";
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{collections::BTreeMap, time::Duration};
+
+    use crate::Part;
+
+    use crate::runner::memory::Allocs;
+
+    #[test]
+    fn test() {
+        let runs = [
+            DayRun {
+                day: 13,
+                parse_time: Duration::from_nanos(5),
+                parts: vec![
+                    (Part::One, Answer::Int(17), Duration::from_nanos(7)),
+                    (
+                        Part::Two,
+                        Answer::Text("#.\n\"#\"".to_string()),
+                        Duration::ZERO,
+                    ),
+                ],
+                error: None,
+                part_allocs: BTreeMap::from([(
+                    Part::One,
+                    Allocs {
+                        count: 2,
+                        bytes: 64,
+                        peak_bytes: 48,
+                    },
+                )]),
+                ..DayRun::default()
+            },
+            DayRun {
+                day: 2,
+                error: Some("could not read input/2021/day2.txt".to_string()),
+                ..DayRun::default()
+            },
+        ];
+        let records = records(&runs);
+        assert_eq!(3, records.len());
+        assert_eq!(Status::Error, records[2].status);
+        assert_eq!(None, records[2].part);
+
+        let json: serde_json::Value = serde_json::from_str(&to_json(&records)).unwrap();
+        assert_eq!(1, json["version"]);
+        assert_eq!("#.\n\"#\"", json["results"][1]["answer"]);
+        assert_eq!(7, json["results"][0]["solve_time_ns"]);
+        assert_eq!("error", json["results"][2]["status"]);
+        assert!(json["results"][2]["solve_time_ns"].is_null());
+        assert_eq!(48, json["results"][0]["peak_bytes"]);
+        assert!(json["results"][1]["allocations"].is_null());
+        assert_eq!(Ok(records.clone()), from_json(&to_json(&records)));
+        assert!(from_json(r#"{"version": 0, "results": []}"#).is_err());
+
+        assert_eq!(
+            "day,part,answer,parse_time_ns,solve_time_ns,status,error,\
+             allocations,allocated_bytes,peak_bytes\r\n\
+             13,1,17,5,7,ok,,2,64,48\r\n\
+             13,2,\"#.\n\"\"#\"\"\",5,0,ok,,,,\r\n\
+             2,,,0,,error,could not read input/2021/day2.txt,,,\r\n",
+            to_csv(&records)
+        );
+        assert_eq!(Some(Format::Csv), Format::parse("csv"));
+        assert_eq!(None, Format::parse("xml"));
+    }
+}
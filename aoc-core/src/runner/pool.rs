@@ -0,0 +1,112 @@
+use std::{
+    collections::VecDeque,
+    sync::{Condvar, Mutex},
+    thread,
+};
+
+pub type Job<'a> = Box<dyn FnOnce(&Pool<'a>) + Send + 'a>;
+
+struct State<'a> {
+    jobs: VecDeque<Job<'a>>,
+    running: usize,
+}
+
+/// A queue of jobs worked through by a fixed number of threads, where jobs can queue more jobs.
+pub struct Pool<'a> {
+    state: Mutex<State<'a>>,
+    changed: Condvar,
+}
+
+/// Marks a job as finished when dropped, even if the job panicked.
+struct Running<'p, 'a>(&'p Pool<'a>);
+
+impl Drop for Running<'_, '_> {
+    fn drop(&mut self) {
+        let mut state = self.0.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.running -= 1;
+        self.0.changed.notify_all();
+    }
+}
+
+impl<'a> Pool<'a> {
+    /// Runs `jobs`, and any jobs they spawn, on `threads` threads, returning once all have finished.
+    pub fn run(threads: usize, jobs: impl IntoIterator<Item = Job<'a>>) {
+        let pool = Pool {
+            state: Mutex::new(State {
+                jobs: jobs.into_iter().collect(),
+                running: 0,
+            }),
+            changed: Condvar::new(),
+        };
+        thread::scope(|s| {
+            for _ in 0..threads {
+                s.spawn(|| pool.work());
+            }
+        });
+    }
+
+    /// Queues a job ahead of the ones already waiting, so that a day's parts are solved
+    /// before the next day is started.
+    pub fn spawn(&self, job: impl FnOnce(&Pool<'a>) + Send + 'a) {
+        let mut state = self.state.lock().unwrap();
+        state.jobs.push_front(Box::new(job));
+        self.changed.notify_one();
+    }
+
+    fn work(&self) {
+        let mut state = self.state.lock().unwrap();
+        loop {
+            if let Some(job) = state.jobs.pop_front() {
+                state.running += 1;
+                drop(state);
+                let running = Running(self);
+                job(self);
+                drop(running);
+                state = self.state.lock().unwrap();
+            } else if state.running == 0 {
+                return;
+            } else {
+                state = self.changed.wait(state).unwrap();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let out = Mutex::new(vec![]);
+        let jobs = (0..10).map(|i| {
+            let out = &out;
+            let job: Job = Box::new(move |pool| {
+                pool.spawn(move |_| out.lock().unwrap().push(i * 10));
+                out.lock().unwrap().push(i);
+            });
+            job
+        });
+        Pool::run(4, jobs);
+
+        let mut out = out.into_inner().unwrap();
+        out.sort();
+        assert_eq!(20, out.len());
+        assert_eq!(90, out[19]);
+
+        let out = Mutex::new(vec![]);
+        let jobs = (0..3).map(|i| {
+            let out = &out;
+            let job: Job = Box::new(move |pool| {
+                pool.spawn(move |_| out.lock().unwrap().push((i, 2)));
+                out.lock().unwrap().push((i, 1));
+            });
+            job
+        });
+        Pool::run(1, jobs);
+        assert_eq!(
+            vec![(0, 1), (0, 2), (1, 1), (1, 2), (2, 1), (2, 2)],
+            out.into_inner().unwrap()
+        );
+    }
+}
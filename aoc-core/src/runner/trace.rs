@@ -0,0 +1,44 @@
+//! Writing out each step of the simulations for `run --trace`.
+
+use std::path::Path;
+
+use crate::{
+    trace::{Gif, ImageFrames, TextFrames},
+    Answer, DynSolution, Params, ParsedInput, Part,
+};
+
+/// Solves a part while writing its steps to `dir`, as text frames in `dayN-partM.txt`, an image
+/// per step in `dayN-partM/` and an animation in `dayN-partM.gif`.
+///
+/// Returns `None` if the day doesn't simulate step by step.
+pub fn solve(
+    dir: &Path,
+    solution: &dyn DynSolution,
+    part: Part,
+    input: &ParsedInput,
+    params: &Params,
+) -> Option<Result<Answer, String>> {
+    let name = format!("day{}-part{}", solution.day(), part);
+    let mut observer = (
+        TextFrames::new(dir.join(format!("{}.txt", name))),
+        (
+            ImageFrames::new(dir.join(&name)),
+            Gif::new(dir.join(format!("{}.gif", name))),
+        ),
+    );
+    let answer = solution.trace(part, input, params, &mut observer)?;
+
+    let (text, (images, gif)) = observer;
+    let written = text
+        .finish()
+        .and_then(|()| images.finish())
+        .and_then(|()| gif.finish());
+    Some(written.map(|()| answer).map_err(|e| {
+        format!(
+            "could not write the trace of {} to {}: {}",
+            name,
+            dir.display(),
+            e
+        )
+    }))
+}
@@ -0,0 +1,208 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{Answer, Part};
+use toml::{Table, Value};
+
+use crate::runner::DayRun;
+
+pub struct VerifyArgs {
+    /// Write the computed answers to the manifest instead of checking them.
+    pub record: bool,
+    pub answers: Option<PathBuf>,
+}
+
+/// The expected answers, as a table of `dayN` tables holding `partN` values.
+pub struct Manifest(Table);
+
+fn day_key(day: u8) -> String {
+    format!("day{}", day)
+}
+
+fn part_key(part: Part) -> String {
+    format!("part{}", part)
+}
+
+impl Manifest {
+    pub fn load(path: &Path) -> Result<Manifest, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("could not read {}: {}", path.display(), e))?;
+        let table = text
+            .parse()
+            .map_err(|e| format!("invalid answers {}: {}", path.display(), e))?;
+        Ok(Manifest(table))
+    }
+
+    /// Loads the manifest to record answers into, starting from nothing if it doesn't exist yet.
+    pub fn load_or_default(path: &Path) -> Result<Manifest, String> {
+        if path.exists() {
+            Manifest::load(path)
+        } else {
+            Ok(Manifest(Table::new()))
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let text = toml::to_string(&self.0).map_err(|e| e.to_string())?;
+        fs::write(path, text).map_err(|e| format!("could not write {}: {}", path.display(), e))
+    }
+
+    pub fn expected(&self, day: u8, part: Part) -> Option<String> {
+        match self.0.get(&day_key(day))?.get(part_key(part))? {
+            Value::String(s) => Some(s.clone()),
+            value => Some(value.to_string()),
+        }
+    }
+
+    pub fn record(&mut self, day: u8, part: Part, answer: &Answer) {
+        let value = match answer {
+            Answer::Int(n) => match i64::try_from(*n) {
+                Ok(n) => Value::Integer(n),
+                Err(_) => Value::String(n.to_string()),
+            },
+            Answer::Big(n) => Value::String(n.to_string()),
+            Answer::Text(s) => Value::String(s.clone()),
+            Answer::None => return,
+        };
+        let day = self
+            .0
+            .entry(day_key(day))
+            .or_insert_with(|| Value::Table(Table::new()));
+        if let Value::Table(day) = day {
+            day.insert(part_key(part), value);
+        }
+    }
+
+    /// Puts the days back in numerical order after new ones were recorded.
+    pub fn sort(&mut self) {
+        let mut days: Vec<_> = std::mem::take(&mut self.0).into_iter().collect();
+        days.sort_by_key(|(key, _)| {
+            let day = key.strip_prefix("day").and_then(|n| n.parse::<u8>().ok());
+            (day.is_none(), day, key.clone())
+        });
+        self.0 = days.into_iter().collect();
+    }
+}
+
+/// Describes how `got` differs from `expected`, line by line for multi-line answers.
+pub fn diff(expected: &str, got: &str) -> String {
+    if !expected.contains('\n') && !got.contains('\n') {
+        return format!("expected {:?}, got {:?}", expected, got);
+    }
+    let (expected, got): (Vec<_>, Vec<_>) = (expected.lines().collect(), got.lines().collect());
+    let mut out = "expected (-) and got (+) differ:".to_string();
+    for i in 0..expected.len().max(got.len()) {
+        match (expected.get(i), got.get(i)) {
+            (Some(e), Some(g)) if e == g => {
+                out += "\n";
+                out += format!("        {}", e).trim_end();
+            }
+            (e, g) => {
+                if let Some(e) = e {
+                    out += &format!("\n      - {}", e);
+                }
+                if let Some(g) = g {
+                    out += &format!("\n      + {}", g);
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Prints a pass/fail table comparing every answer with the manifest, returning whether any failed.
+///
+/// A part with no recorded answer fails the check too, so that a manifest that's lost an entry
+/// can't pass by accident. `--record` is how new answers get into it.
+pub fn check(manifest: &Manifest, runs: &[DayRun]) -> bool {
+    let mut failed = false;
+    let (mut passed, mut total) = (0, 0);
+    println!("{:>3}  {:>4}  status", "day", "part");
+    for run in runs {
+        if let Some(e) = &run.error {
+            println!("{:>3}  {:>4}  ERROR    {}", run.day, "", e);
+            failed = true;
+            continue;
+        }
+        for (part, failure) in &run.part_failures {
+            total += 1;
+            failed = true;
+            println!("{:>3}  {:>4}  FAILED   {}", run.day, part.number(), failure);
+        }
+        for (part, answer, _) in run.parts.iter().filter(|(_, a, _)| *a != Answer::None) {
+            total += 1;
+            let got = answer.to_string();
+            match manifest.expected(run.day, *part) {
+                None => {
+                    failed = true;
+                    println!(
+                        "{:>3}  {:>4}  MISSING  got {:?}",
+                        run.day,
+                        part.number(),
+                        got
+                    );
+                }
+                Some(expected) if expected == got => {
+                    passed += 1;
+                    println!("{:>3}  {:>4}  pass", run.day, part.number());
+                }
+                Some(expected) => {
+                    failed = true;
+                    println!(
+                        "{:>3}  {:>4}  FAIL     {}",
+                        run.day,
+                        part.number(),
+                        diff(&expected, &got)
+                    );
+                }
+            }
+        }
+    }
+    println!("\n{} of {} answers passed", passed, total);
+    failed
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test() {
+        let mut manifest = Manifest(Table::new());
+        manifest.record(10, Part::One, &Answer::Int(7));
+        manifest.record(2, Part::Two, &Answer::Int(i128::MAX));
+        manifest.record(2, Part::One, &Answer::Text("#.\n.#".to_string()));
+        manifest.record(2, Part::One, &Answer::None);
+        manifest.sort();
+
+        let manifest = Manifest(toml::to_string(&manifest.0).unwrap().parse().unwrap());
+        assert_eq!(Some("7".to_string()), manifest.expected(10, Part::One));
+        assert_eq!(Some(i128::MAX.to_string()), manifest.expected(2, Part::Two));
+        assert_eq!(Some("#.\n.#".to_string()), manifest.expected(2, Part::One));
+        assert_eq!(None, manifest.expected(10, Part::Two));
+        assert_eq!(vec!["day2", "day10"], manifest.0.keys().collect::<Vec<_>>());
+
+        let run = |parts: &[Part]| DayRun {
+            day: 10,
+            parts: parts
+                .iter()
+                .map(|&part| (part, Answer::Int(7), Duration::ZERO))
+                .collect(),
+            ..DayRun::default()
+        };
+        assert!(!check(&manifest, &[run(&[Part::One])]));
+        // day 10 has no answer for part 2
+        assert!(check(&manifest, &[run(&Part::ALL)]));
+        assert!(check(&Manifest(Table::new()), &[run(&[Part::One])]));
+
+        assert_eq!("expected \"1\", got \"2\"", diff("1", "2"));
+        assert_eq!(
+            "expected (-) and got (+) differ:\n        #.\n      - .#\n      + ##",
+            diff("#.\n.#", "#.\n##")
+        );
+    }
+}
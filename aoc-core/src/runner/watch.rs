@@ -0,0 +1,203 @@
+//! Re-running a day whenever its source or input changes, for `watch`.
+//!
+//! The runner can't load new code into itself, so each run goes through `cargo run`, which
+//! rebuilds whatever changed and prints the day's results as JSON for this to read back.
+
+use std::{
+    collections::BTreeMap,
+    env, fs, io,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::runner::output::{self, Record, Status};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// When each watched file was last modified, or `None` if it doesn't exist.
+type Mtimes = BTreeMap<PathBuf, Option<SystemTime>>;
+
+fn mtime(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Adds every file under `dir`, which may not exist (yet).
+fn add_dir(mtimes: &mut Mtimes, dir: &Path) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for path in entries.filter_map(|entry| Some(entry.ok()?.path())) {
+        if path.is_dir() {
+            add_dir(mtimes, &path);
+        } else {
+            mtimes.insert(path.clone(), mtime(&path));
+        }
+    }
+}
+
+fn poll(dir: &Path, input: &Path) -> Mtimes {
+    let mut mtimes = Mtimes::new();
+    add_dir(&mut mtimes, dir);
+    mtimes.insert(input.to_path_buf(), mtime(input));
+    mtimes
+}
+
+/// The files that were added, removed or modified between two polls.
+fn changes<'a>(before: &'a Mtimes, after: &'a Mtimes) -> Vec<&'a Path> {
+    let mut changed: Vec<_> = after
+        .iter()
+        .filter(|(path, mtime)| before.get(*path) != Some(mtime))
+        .map(|(path, _)| path.as_path())
+        .collect();
+    changed.extend(
+        before
+            .keys()
+            .filter(|path| !after.contains_key(*path))
+            .map(PathBuf::as_path),
+    );
+    changed
+}
+
+/// Builds and runs the day with `args`, returning its records, or `None` if the build failed.
+fn run(args: &[String]) -> io::Result<Option<Vec<Record>>> {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let mut command = Command::new(cargo);
+    command.args(["run", "--quiet", "--bin", "bin"]);
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    command.arg("--").arg("run").args(args);
+    command.args(["--format", "json"]);
+    // let cargo's own output, like compile errors, through as it happens
+    let output = command.stderr(Stdio::inherit()).output()?;
+    Ok(output::from_json(&String::from_utf8_lossy(&output.stdout)).ok())
+}
+
+fn nanos(ns: u64) -> String {
+    format!("{:.2?}", Duration::from_nanos(ns))
+}
+
+/// Lays out a run's results, with the previous run's next to any that changed.
+fn report(records: &[Record], previous: Option<&[Record]>) -> String {
+    let mut out = String::new();
+    let find = |part| previous?.iter().find(|r: &&Record| r.part == part);
+    if let Some(first) = records.first() {
+        let was = match previous.and_then(<[Record]>::first) {
+            Some(before) => format!(" (was {})", nanos(before.parse_time_ns)),
+            None => String::new(),
+        };
+        out += &format!("  parsed in {}{}\n", nanos(first.parse_time_ns), was);
+    }
+    for record in records {
+        let Some(part) = record.part else {
+            let error = record.error.as_deref().unwrap_or_default();
+            out += &format!("  error: {}\n", error);
+            continue;
+        };
+        let time = record.solve_time_ns.map(nanos).unwrap_or_default();
+        let answer = match record.status {
+            Status::Ok => record.answer.clone().unwrap_or_default(),
+            _ => format!("FAILED, {}", record.error.as_deref().unwrap_or_default()),
+        };
+        out += &format!("  part {} in {}", part, time);
+        let Some(before) = find(Some(part)) else {
+            out += &format!(": {}\n", answer);
+            continue;
+        };
+        let was_time = before.solve_time_ns.map(nanos).unwrap_or_default();
+        out += &format!(" (was {}): {}", was_time, answer);
+        if before.answer != record.answer || before.status != record.status {
+            let was = before.answer.as_deref().unwrap_or("nothing");
+            out += &format!(" (CHANGED from {})", was);
+        }
+        out += "\n";
+    }
+    out
+}
+
+/// Runs the day with `args` every time a file in `dir` or `input` changes, until interrupted.
+pub fn watch(day: u8, dir: &Path, input: &Path, args: &[String]) -> ! {
+    let cwd = env::current_dir().unwrap_or_default();
+    let show = |path: &Path| {
+        path.strip_prefix(&cwd)
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    };
+    println!("watching {} and {}", show(dir), show(input));
+
+    let mut previous: Option<Vec<Record>> = None;
+    let mut mtimes = Mtimes::new();
+    loop {
+        let polled = poll(dir, input);
+        let changed = changes(&mtimes, &polled);
+        if changed.is_empty() {
+            thread::sleep(POLL_INTERVAL);
+            continue;
+        }
+        if !mtimes.is_empty() {
+            let changed: Vec<_> = changed.iter().map(|path| show(path)).collect();
+            println!("\n{} changed", changed.join(", "));
+        }
+        // anything saved while this builds is picked up by the next poll
+        mtimes = polled;
+        println!("day {}", day);
+        match run(args) {
+            Ok(Some(records)) => {
+                print!("{}", report(&records, previous.as_deref()));
+                previous = Some(records);
+            }
+            Ok(None) => println!("  the build failed, waiting for the next change"),
+            Err(e) => println!("  could not run cargo: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let time = |secs| Some(SystemTime::UNIX_EPOCH + Duration::from_secs(secs));
+        let before = Mtimes::from([("a".into(), time(1)), ("b".into(), time(1))]);
+        let after = Mtimes::from([("a".into(), time(2)), ("c".into(), None)]);
+        let changed = changes(&before, &after);
+        assert_eq!(
+            vec![Path::new("a"), Path::new("c"), Path::new("b")],
+            changed
+        );
+        assert!(changes(&after, &after).is_empty());
+
+        let record = |part, answer: &str, ns| Record {
+            day: 6,
+            part: Some(part),
+            answer: Some(answer.to_string()),
+            parse_time_ns: 1_000,
+            solve_time_ns: Some(ns),
+            status: Status::Ok,
+            error: None,
+            allocations: None,
+            allocated_bytes: None,
+            peak_bytes: None,
+        };
+        let first = [record(1, "5934", 2_000), record(2, "26984457539", 3_000)];
+        assert_eq!(
+            "  parsed in 1.00µs\n  part 1 in 2.00µs: 5934\n  part 2 in 3.00µs: 26984457539\n",
+            report(&first, None)
+        );
+        let second = [record(1, "5934", 1_500), record(2, "12", 3_000)];
+        let lines: Vec<_> = report(&second, Some(&first))
+            .lines()
+            .map(String::from)
+            .collect();
+        assert_eq!("  parsed in 1.00µs (was 1.00µs)", lines[0]);
+        assert_eq!("  part 1 in 1.50µs (was 2.00µs): 5934", lines[1]);
+        assert_eq!(
+            "  part 2 in 3.00µs (was 3.00µs): 12 (CHANGED from 26984457539)",
+            lines[2]
+        );
+    }
+}
@@ -0,0 +1,126 @@
+//! Keeping one part's panic or hang from stopping the rest of the run.
+
+use std::{
+    any::Any,
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+    sync::mpsc,
+    thread,
+    time::Duration,
+};
+
+use crate::cancel::{self, Token};
+
+/// Why a part has no answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    /// The part panicked with this message.
+    Panicked(String),
+    /// The part was still running when its time ran out.
+    TimedOut(Duration),
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::Panicked(message) => write!(f, "panicked: {}", message),
+            Failure::TimedOut(timeout) => write!(f, "timed out after {:?}", timeout),
+        }
+    }
+}
+
+pub fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "unknown panic".to_string(),
+        },
+    }
+}
+
+/// Runs `f` without printing the panics it catches, since they're reported with the results.
+pub fn quiet_panics<T>(f: impl FnOnce() -> T) -> T {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    panic::set_hook(hook);
+    result.unwrap_or_else(|payload| panic::resume_unwind(payload))
+}
+
+/// Runs parts with their panics caught, giving up on any that run for longer than the timeout.
+///
+/// With a timeout, each part runs on a thread of its own, and is reported as timed out as soon as
+/// its time is up, so one slow part can't hold up the rest of the run. Its [`Token`] is cancelled
+/// then, which stops the searches that call [`cancel::check`]; anything else carries on in the
+/// background until it finishes or the runner exits.
+pub struct Watchdog {
+    timeout: Option<Duration>,
+}
+
+impl Watchdog {
+    pub fn new(timeout: Option<Duration>) -> Watchdog {
+        Watchdog { timeout }
+    }
+
+    /// Runs `f`, returning why it failed if it panicked or ran out of time.
+    pub fn run<T: Send + 'static>(
+        &self,
+        f: impl FnOnce() -> T + Send + 'static,
+    ) -> Result<T, Failure> {
+        let token = Token::new();
+        let caught = {
+            let token = token.clone();
+            move || panic::catch_unwind(AssertUnwindSafe(|| cancel::with_token(&token, f)))
+        };
+        let Some(timeout) = self.timeout else {
+            return caught().map_err(|payload| Failure::Panicked(panic_message(payload)));
+        };
+
+        let (sender, receiver) = mpsc::channel();
+        // nothing waits for the thread, and once the time is up nothing is listening for it either
+        thread::spawn(move || sender.send(caught()).ok());
+        match receiver.recv_timeout(timeout) {
+            Ok(Ok(value)) => Ok(value),
+            Ok(Err(payload)) => Err(Failure::Panicked(panic_message(payload))),
+            Err(_) => {
+                token.cancel();
+                Err(Failure::TimedOut(timeout))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use super::*;
+
+    #[test]
+    fn test() {
+        let watchdog = Watchdog::new(None);
+        assert_eq!(Ok(4), watchdog.run(|| 2 + 2));
+        let failure = quiet_panics(|| watchdog.run(|| panic!("out of {}", "cheese")));
+        assert_eq!(Err(Failure::Panicked("out of cheese".to_string())), failure);
+
+        let timeout = Duration::from_millis(20);
+        let watchdog = Watchdog::new(Some(timeout));
+        assert_eq!(Ok(()), watchdog.run(|| ()));
+        let spin = || loop {
+            cancel::check();
+        };
+        assert_eq!(Err(Failure::TimedOut(timeout)), watchdog.run(spin));
+        // a part that never checks is still reported once its time is up
+        let instant = Instant::now();
+        let sleep = move || thread::sleep(timeout * 100);
+        assert_eq!(Err(Failure::TimedOut(timeout)), watchdog.run(sleep));
+        assert!(instant.elapsed() < timeout * 50);
+        let failure = quiet_panics(|| watchdog.run(|| panic!("out of {}", "cheese")));
+        assert_eq!(Err(Failure::Panicked("out of cheese".to_string())), failure);
+        assert_eq!(
+            "timed out after 20ms",
+            Failure::TimedOut(timeout).to_string()
+        );
+    }
+}
@@ -0,0 +1,457 @@
+//! Watching the step-by-step simulations (days 11, 13, 20, 23 and 25) as they run, and writing
+//! what they did to text, image sequences or an animated GIF.
+
+use std::{
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+};
+
+use rustc_hash::FxHashMap;
+
+use crate::grid::Grid;
+
+/// The state of a simulation after one of its steps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    /// How many steps have been taken, so the starting state is step 0.
+    pub step: usize,
+    /// The state drawn the way the puzzle draws it.
+    pub text: String,
+    /// The shade of each character of `text`, from 0 for the background up to `levels - 1`.
+    pub image: Grid<u8>,
+    pub levels: u8,
+}
+
+impl Snapshot {
+    /// Makes a snapshot of a state drawn as text, shading each of its characters.
+    ///
+    /// Short lines are padded out with the background.
+    pub fn new(step: usize, text: String, levels: u8, shade: impl Fn(char) -> u8) -> Snapshot {
+        let rows: Vec<Vec<u8>> = text
+            .lines()
+            .map(|line| line.chars().map(&shade).collect())
+            .collect();
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let image = Grid::from_fn(width, rows.len(), |(x, y)| {
+            rows[y].get(x).map_or(0, |&shade| shade.min(levels - 1))
+        });
+        Snapshot {
+            step,
+            text,
+            image,
+            levels,
+        }
+    }
+}
+
+/// Something that watches a simulation, seeing a [`Snapshot`] of the starting state and then one
+/// after every step.
+pub trait Observer {
+    /// Whether the observer wants snapshots at all, so simulations can skip making them.
+    fn is_watching(&self) -> bool {
+        true
+    }
+
+    fn observe(&mut self, snapshot: &Snapshot);
+}
+
+/// Watches nothing, for when a simulation is only run for its answer.
+impl Observer for () {
+    fn is_watching(&self) -> bool {
+        false
+    }
+
+    fn observe(&mut self, _: &Snapshot) {}
+}
+
+/// Keeps every snapshot.
+impl Observer for Vec<Snapshot> {
+    fn observe(&mut self, snapshot: &Snapshot) {
+        self.push(snapshot.clone());
+    }
+}
+
+/// Shows each snapshot to both observers.
+impl<A: Observer, B: Observer> Observer for (A, B) {
+    fn is_watching(&self) -> bool {
+        self.0.is_watching() || self.1.is_watching()
+    }
+
+    fn observe(&mut self, snapshot: &Snapshot) {
+        self.0.observe(snapshot);
+        self.1.observe(snapshot);
+    }
+}
+
+/// Shows `observer` a snapshot, only making it if the observer is watching.
+pub fn show(observer: &mut dyn Observer, snapshot: impl FnOnce() -> Snapshot) {
+    if observer.is_watching() {
+        observer.observe(&snapshot());
+    }
+}
+
+/// Keeps the first error from writing frames, since observers can't return one.
+fn keep_error(error: &mut Option<io::Error>, write: impl FnOnce() -> io::Result<()>) {
+    if error.is_none() {
+        *error = write().err();
+    }
+}
+
+/// Writes every snapshot's text to one file, one frame after another.
+///
+/// The file is only created once there's a snapshot to write.
+pub struct TextFrames {
+    path: PathBuf,
+    out: Option<BufWriter<File>>,
+    error: Option<io::Error>,
+}
+
+impl TextFrames {
+    pub fn new(path: impl Into<PathBuf>) -> TextFrames {
+        TextFrames {
+            path: path.into(),
+            out: None,
+            error: None,
+        }
+    }
+
+    /// Flushes the file, returning the first error from writing it.
+    pub fn finish(self) -> io::Result<()> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        self.out.map_or(Ok(()), |mut out| out.flush())
+    }
+}
+
+impl Observer for TextFrames {
+    fn observe(&mut self, snapshot: &Snapshot) {
+        let (path, out) = (&self.path, &mut self.out);
+        keep_error(&mut self.error, || {
+            if out.is_none() {
+                *out = Some(BufWriter::new(File::create(path)?));
+            }
+            let out = out.as_mut().unwrap();
+            writeln!(
+                out,
+                "step {}:\n{}\n",
+                snapshot.step,
+                snapshot.text.trim_end()
+            )
+        });
+    }
+}
+
+/// Writes each snapshot's image to a directory as `NNNN.pbm` when it's black and white, or as
+/// `NNNN.pgm` in shades of grey, numbered by step.
+///
+/// The background is white and higher shades are darker. The directory is only created once
+/// there's a snapshot to write.
+pub struct ImageFrames {
+    dir: PathBuf,
+    created: bool,
+    error: Option<io::Error>,
+}
+
+impl ImageFrames {
+    pub fn new(dir: impl Into<PathBuf>) -> ImageFrames {
+        ImageFrames {
+            dir: dir.into(),
+            created: false,
+            error: None,
+        }
+    }
+
+    /// Returns the first error from writing the images.
+    pub fn finish(self) -> io::Result<()> {
+        self.error.map_or(Ok(()), Err)
+    }
+}
+
+/// Encodes an image as a binary PBM if it has two shades, or as a binary PGM otherwise.
+pub fn netpbm(image: &Grid<u8>, levels: u8) -> Vec<u8> {
+    let (width, height) = (image.width(), image.height());
+    if levels <= 2 {
+        let mut out = format!("P4\n{} {}\n", width, height).into_bytes();
+        for y in 0..height {
+            // each row is packed into bits, 1 for black, and padded to a whole byte
+            for start in (0..width).step_by(8) {
+                let byte = (start..width.min(start + 8)).fold(0, |byte, x| {
+                    byte | ((image[(x, y)] > 0) as u8) << (7 - (x - start))
+                });
+                out.push(byte);
+            }
+        }
+        out
+    } else {
+        let max = levels - 1;
+        let mut out = format!("P5\n{} {}\n{}\n", width, height, max).into_bytes();
+        out.extend(image.iter().map(|&shade| max - shade));
+        out
+    }
+}
+
+impl Observer for ImageFrames {
+    fn observe(&mut self, snapshot: &Snapshot) {
+        let (dir, created) = (&self.dir, &mut self.created);
+        keep_error(&mut self.error, || {
+            if !*created {
+                fs::create_dir_all(dir)?;
+                *created = true;
+            }
+            let extension = if snapshot.levels <= 2 { "pbm" } else { "pgm" };
+            let path = dir.join(format!("{:04}.{}", snapshot.step, extension));
+            fs::write(path, netpbm(&snapshot.image, snapshot.levels))
+        });
+    }
+}
+
+/// Collects the snapshots' images into an animated GIF, written by [`Gif::finish`].
+pub struct Gif {
+    path: PathBuf,
+    /// How long to show each frame, in hundredths of a second.
+    pub delay: u16,
+    frames: Vec<Grid<u8>>,
+    levels: u8,
+}
+
+impl Gif {
+    pub fn new(path: impl Into<PathBuf>) -> Gif {
+        Gif {
+            path: path.into(),
+            delay: 10,
+            frames: vec![],
+            levels: 2,
+        }
+    }
+
+    /// Writes the animation, if there were any snapshots to animate.
+    pub fn finish(self) -> io::Result<()> {
+        if self.frames.is_empty() {
+            return Ok(());
+        }
+        let mut out = BufWriter::new(File::create(&self.path)?);
+        write_gif(&mut out, &self.frames, self.levels, self.delay)?;
+        out.flush()
+    }
+}
+
+impl Observer for Gif {
+    fn observe(&mut self, snapshot: &Snapshot) {
+        self.frames.push(snapshot.image.clone());
+        self.levels = self.levels.max(snapshot.levels);
+    }
+}
+
+/// The most codes the LZW compression in a GIF can have.
+const MAX_CODES: u16 = 4096;
+
+/// Packs variable-length codes into bytes, least significant bit first.
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    len: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, size: u32) {
+        self.buffer |= (code as u32) << self.len;
+        self.len += size;
+        while self.len >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.len -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.len > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+/// Compresses an image's color indexes with the variable-length LZW coding GIFs use.
+fn lzw(indexes: &[u8], min_code_size: u32) -> Vec<u8> {
+    let clear = 1 << min_code_size;
+    let end = clear + 1;
+    let mut codes: FxHashMap<(u16, u8), u16> = FxHashMap::default();
+    let mut next = end + 1;
+    let mut size = min_code_size + 1;
+    let mut bits = BitWriter {
+        bytes: vec![],
+        buffer: 0,
+        len: 0,
+    };
+
+    bits.write(clear, size);
+    let mut indexes = indexes.iter();
+    let Some(&first) = indexes.next() else {
+        bits.write(end, size);
+        return bits.finish();
+    };
+    // the code for the run of indexes read so far
+    let mut run = first as u16;
+    for &index in indexes {
+        if let Some(&code) = codes.get(&(run, index)) {
+            run = code;
+            continue;
+        }
+        bits.write(run, size);
+        codes.insert((run, index), next);
+        // the decoder widens its codes as soon as the next one it adds wouldn't fit
+        if next >= 1 << size {
+            size += 1;
+        }
+        next += 1;
+        if next == MAX_CODES {
+            bits.write(clear, size);
+            codes.clear();
+            next = end + 1;
+            size = min_code_size + 1;
+        }
+        run = index as u16;
+    }
+    bits.write(run, size);
+    bits.write(end, size);
+    bits.finish()
+}
+
+/// Writes images as the frames of an endlessly looping GIF, shading them in grey like
+/// [`ImageFrames`]. Smaller frames are padded out with the background.
+pub fn write_gif(
+    out: &mut impl Write,
+    frames: &[Grid<u8>],
+    levels: u8,
+    delay: u16,
+) -> io::Result<()> {
+    let too_big = || io::Error::new(io::ErrorKind::InvalidInput, "frames are too big for a GIF");
+    let width = frames.iter().map(Grid::width).max().unwrap_or(0);
+    let height = frames.iter().map(Grid::height).max().unwrap_or(0);
+    let (width, height) = (
+        u16::try_from(width).map_err(|_| too_big())?,
+        u16::try_from(height).map_err(|_| too_big())?,
+    );
+
+    // the color table needs a power of two entries, and at least 2 bits of them for the LZW
+    let levels = levels.max(2);
+    let table_bits = (u8::BITS - (levels - 1).leading_zeros()).max(1);
+    let min_code_size = table_bits.max(2);
+
+    out.write_all(b"GIF89a")?;
+    out.write_all(&width.to_le_bytes())?;
+    out.write_all(&height.to_le_bytes())?;
+    // a global color table, with the color resolution and table size in bits less one
+    let bits = table_bits as u8 - 1;
+    out.write_all(&[0x80 | bits << 4 | bits, 0, 0])?;
+    for i in 0..1u16 << table_bits {
+        let shade = i.min(levels as u16 - 1);
+        let grey = (255 - shade * 255 / (levels as u16 - 1)) as u8;
+        out.write_all(&[grey; 3])?;
+    }
+    // loop forever
+    out.write_all(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00")?;
+
+    for frame in frames {
+        out.write_all(&[0x21, 0xf9, 4, 0])?;
+        out.write_all(&delay.to_le_bytes())?;
+        out.write_all(&[0, 0])?;
+
+        out.write_all(&[0x2c, 0, 0, 0, 0])?;
+        out.write_all(&width.to_le_bytes())?;
+        out.write_all(&height.to_le_bytes())?;
+        out.write_all(&[0])?;
+        let indexes: Vec<u8> = (0..height as usize)
+            .flat_map(|y| (0..width as usize).map(move |x| (x, y)))
+            .map(|p| frame.get(p).copied().unwrap_or(0))
+            .collect();
+        out.write_all(&[min_code_size as u8])?;
+        for block in lzw(&indexes, min_code_size).chunks(255) {
+            out.write_all(&[block.len() as u8])?;
+            out.write_all(block)?;
+        }
+        out.write_all(&[0])?;
+    }
+    out.write_all(&[0x3b])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Decodes LZW data the way the GIF specification describes, independently of [`lzw`].
+    fn unlzw(bytes: &[u8], min_code_size: u32) -> Vec<u8> {
+        let clear = 1 << min_code_size;
+        let end = clear + 1;
+        let reset = || -> Vec<Vec<u8>> { (0..=end).map(|i| vec![i as u8]).collect() };
+        let (mut table, mut size, mut prev) = (reset(), min_code_size + 1, None::<usize>);
+        let (mut out, mut pos) = (vec![], 0);
+        loop {
+            let code = (0..size as usize).fold(0, |code, i| {
+                let bit = bytes[(pos + i) / 8] >> ((pos + i) % 8) & 1;
+                code | (bit as usize) << i
+            });
+            pos += size as usize;
+            if code == clear {
+                (table, size, prev) = (reset(), min_code_size + 1, None);
+                continue;
+            }
+            if code == end {
+                return out;
+            }
+            let entry = match prev {
+                None => table[code].clone(),
+                Some(prev) => {
+                    let entry = match table.get(code) {
+                        Some(entry) => entry.clone(),
+                        None => [&table[prev][..], &table[prev][..1]].concat(),
+                    };
+                    table.push([&table[prev][..], &entry[..1]].concat());
+                    if table.len() == 1 << size && size < 12 {
+                        size += 1;
+                    }
+                    entry
+                }
+            };
+            out.extend(&entry);
+            prev = Some(code);
+        }
+    }
+
+    #[test]
+    fn test() {
+        let snapshot = Snapshot::new(3, "#.\n.##\n".to_string(), 2, |c| (c == '#') as u8);
+        assert_eq!((3, 2), (snapshot.image.width(), snapshot.image.height()));
+        assert_eq!(
+            vec![1, 0, 0, 0, 1, 1],
+            snapshot.image.iter().copied().collect::<Vec<_>>()
+        );
+
+        assert_eq!(b"P4\n3 2\n\x80\x60".to_vec(), netpbm(&snapshot.image, 2));
+        let grey = Grid::new(2, 1, vec![0, 2]);
+        assert_eq!(b"P5\n2 1\n2\n\x02\x00".to_vec(), netpbm(&grey, 3));
+
+        // long enough to fill the code table and start again
+        let mut rng = crate::rng::Rng::new(1);
+        let indexes: Vec<u8> = (0..50_000)
+            .map(|i| (rng.below(2) + i % 7 / 3) as u8)
+            .collect();
+        for min_code_size in [2, 3, 8] {
+            let decoded = unlzw(&lzw(&indexes, min_code_size), min_code_size);
+            assert_eq!(indexes, decoded);
+        }
+        assert_eq!(Vec::<u8>::new(), unlzw(&lzw(&[], 2), 2));
+
+        let mut gif = vec![];
+        write_gif(&mut gif, &[snapshot.image.clone(), grey], 3, 5).unwrap();
+        assert!(gif.starts_with(b"GIF89a\x03\x00\x02\x00"));
+        assert_eq!(Some(&0x3b), gif.last());
+
+        let mut observer = (Vec::new(), ());
+        assert!(observer.is_watching());
+        show(&mut observer, || snapshot.clone());
+        assert_eq!(vec![snapshot], observer.0);
+    }
+}
@@ -0,0 +1,13 @@
+use std::process::ExitCode;
+
+use aoc_core::{runner, Year};
+
+/// Every year that was built in, for `--year` to pick from.
+static YEARS: &[&Year] = &[
+    #[cfg(feature = "2021")]
+    &advent_of_code_2021::YEAR,
+];
+
+fn main() -> ExitCode {
+    runner::main(YEARS)
+}
@@ -0,0 +1,12 @@
+#![no_main]
+
+use advent_of_code_2021::day_10;
+use libfuzzer_sys::fuzz_target;
+
+// the parts walk what was parsed, so they're fuzzed along with the parser
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = day_10::parse(&String::from_utf8_lossy(data)) {
+        day_10::part_1(&input);
+        day_10::part_2(&input);
+    }
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use advent_of_code_2021::day_12;
+use libfuzzer_sys::fuzz_target;
+
+// counting paths takes exponential time on arbitrary graphs, so only the parser is fuzzed
+fuzz_target!(|data: &[u8]| {
+    let _ = day_12::parse(&String::from_utf8_lossy(data));
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use advent_of_code_2021::day_16;
+use libfuzzer_sys::fuzz_target;
+
+// the parts walk what was parsed, so they're fuzzed along with the parser
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = day_16::parse(&String::from_utf8_lossy(data)) {
+        day_16::part_1(&input);
+        day_16::part_2(&input);
+    }
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+use advent_of_code_2021::day_24;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(blocks) = day_24::parse(&String::from_utf8_lossy(data)) {
+        day_24::part_1(&blocks);
+        day_24::part_2(&blocks);
+    }
+});